@@ -0,0 +1,158 @@
+use crate::error::AocError;
+use crate::solution::{Day, Part};
+use serde::Serialize;
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Some(Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+/*
+ * One line of the report, flat so it maps directly to both CSV and JSON
+ */
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Row {
+    pub day: u8,
+    pub phase: &'static str,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Row {
+    fn new(day: u8, phase: &'static str, runs: usize, stats: Stats) -> Self {
+        Row {
+            day,
+            phase,
+            runs,
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            max_ns: stats.max.as_nanos() as u64,
+        }
+    }
+}
+
+// solve the day `runs` times and summarize parse, part 1 and part 2 separately
+pub fn bench(day: &Day, input: &[u8], runs: usize) -> Result<Vec<Row>, AocError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let answers = (day.run)(input, &[Part::One, Part::Two])?;
+        parse.push(answers.parse_elapsed);
+        for answer in answers.parts {
+            match answer.part {
+                Part::One => part1.push(answer.elapsed),
+                Part::Two => part2.push(answer.elapsed),
+            }
+        }
+    }
+
+    Ok(vec![("parse", parse), ("part1", part1), ("part2", part2)]
+        .into_iter()
+        .filter_map(|(phase, mut samples)| {
+            Stats::from_samples(&mut samples).map(|stats| Row::new(day.day, phase, runs, stats))
+        })
+        .collect())
+}
+
+pub struct Table<'a>(pub &'a [Row]);
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Day  Phase  {:>12}  {:>12}  {:>12}",
+            "min", "median", "max"
+        )?;
+        for row in self.0 {
+            writeln!(
+                f,
+                "{:>3}  {:<5}  {:>12?}  {:>12?}  {:>12?}",
+                row.day,
+                row.phase,
+                Duration::from_nanos(row.min_ns),
+                Duration::from_nanos(row.median_ns),
+                Duration::from_nanos(row.max_ns)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub fn to_csv(rows: &[Row]) -> String {
+    let mut csv = String::from("day,phase,runs,min_ns,median_ns,max_ns\n");
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            row.day, row.phase, row.runs, row.min_ns, row.median_ns, row.max_ns
+        ));
+    }
+    csv
+}
+
+pub fn to_json(rows: &[Row]) -> String {
+    // rows hold only plain numbers and static names, serialization cannot fail
+    serde_json::to_string_pretty(rows).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_csv, Row, Stats};
+    use std::time::Duration;
+
+    #[test]
+    fn test_stats() {
+        let mut samples: Vec<Duration> = [5, 1, 3, 2]
+            .iter()
+            .map(|&n| Duration::from_nanos(n))
+            .collect();
+        let stats = Stats::from_samples(&mut samples).unwrap();
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(2));
+        assert_eq!(stats.max, Duration::from_nanos(5));
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_csv() {
+        let rows = vec![Row {
+            day: 6,
+            phase: "part1",
+            runs: 3,
+            min_ns: 10,
+            median_ns: 20,
+            max_ns: 30,
+        }];
+        assert_eq!(
+            to_csv(&rows),
+            "day,phase,runs,min_ns,median_ns,max_ns\n6,part1,3,10,20,30\n"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use std::fmt::{Display, Formatter};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/*
+ * Compact set of cells in a fixed rectangle, one bit per cell stored row by row
+ * Bits past the last cell are always kept zero, so words can be combined directly
+ */
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BitArray {
+    data: Vec<u64>,
+    size_x: usize,
+    size_y: usize,
+}
+
+impl BitArray {
+    pub fn new(bit_size_x: usize, bit_size_y: usize) -> Self {
+        let data = vec![0; (bit_size_x * bit_size_y).div_ceil(WORD_BITS)];
+        Self {
+            data,
+            size_x: bit_size_x,
+            size_y: bit_size_y,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.size_x
+    }
+
+    pub fn height(&self) -> usize {
+        self.size_y
+    }
+
+    // word index and mask of the bit, None when out of bounds
+    fn locate(&self, x: usize, y: usize) -> Option<(usize, u64)> {
+        if x < self.size_x && y < self.size_y {
+            let index = x + y * self.size_x;
+            Some((index / WORD_BITS, 1 << (index % WORD_BITS)))
+        } else {
+            None
+        }
+    }
+
+    // returns the old value, None (and no change) when out of bounds
+    pub fn set_bit(&mut self, x: usize, y: usize) -> Option<bool> {
+        let (word, mask) = self.locate(x, y)?;
+        let old = self.data[word] & mask != 0;
+        self.data[word] |= mask;
+        Some(old)
+    }
+
+    pub fn clear_bit(&mut self, x: usize, y: usize) -> Option<bool> {
+        let (word, mask) = self.locate(x, y)?;
+        let old = self.data[word] & mask != 0;
+        self.data[word] &= !mask;
+        Some(old)
+    }
+
+    pub fn toggle(&mut self, x: usize, y: usize) -> Option<bool> {
+        let (word, mask) = self.locate(x, y)?;
+        let old = self.data[word] & mask != 0;
+        self.data[word] ^= mask;
+        Some(old)
+    }
+
+    // cells outside the rectangle are never set
+    pub fn check_bit(&self, x: usize, y: usize) -> bool {
+        self.locate(x, y)
+            .is_some_and(|(word, mask)| self.data[word] & mask != 0)
+    }
+
+    pub fn sum_bits(&self) -> usize {
+        self.data
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    // set bits with index in start..end
+    fn count_range(&self, start: usize, end: usize) -> usize {
+        if start >= end {
+            return 0;
+        }
+        let (first, last) = (start / WORD_BITS, (end - 1) / WORD_BITS);
+        let head_mask = u64::MAX << (start % WORD_BITS);
+        let tail_mask = u64::MAX >> (WORD_BITS - 1 - (end - 1) % WORD_BITS);
+        if first == last {
+            return (self.data[first] & head_mask & tail_mask).count_ones() as usize;
+        }
+        let middle: u32 = self.data[first + 1..last]
+            .iter()
+            .map(|word| word.count_ones())
+            .sum();
+        ((self.data[first] & head_mask).count_ones()
+            + middle
+            + (self.data[last] & tail_mask).count_ones()) as usize
+    }
+
+    pub fn row_count(&self, y: usize) -> Option<usize> {
+        if y < self.size_y {
+            Some(self.count_range(y * self.size_x, (y + 1) * self.size_x))
+        } else {
+            None
+        }
+    }
+
+    pub fn column_count(&self, x: usize) -> Option<usize> {
+        if x < self.size_x {
+            Some((0..self.size_y).filter(|&y| self.check_bit(x, y)).count())
+        } else {
+            None
+        }
+    }
+
+    /*
+     * Coordinates of set bits, row by row, skipping empty words
+     */
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let size_x = self.size_x;
+        self.data
+            .iter()
+            .enumerate()
+            .flat_map(|(word_idx, &word)| {
+                let mut rest = word;
+                std::iter::from_fn(move || {
+                    if rest == 0 {
+                        return None;
+                    }
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    Some(word_idx * WORD_BITS + bit)
+                })
+            })
+            .map(move |index| (index % size_x, index / size_x))
+    }
+
+    // combine word by word, None when the rectangles differ in size
+    fn combine<F: Fn(u64, u64) -> u64>(&self, other: &BitArray, op: F) -> Option<BitArray> {
+        if (self.size_x, self.size_y) != (other.size_x, other.size_y) {
+            return None;
+        }
+        Some(BitArray {
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(&a, &b)| op(a, b))
+                .collect(),
+            size_x: self.size_x,
+            size_y: self.size_y,
+        })
+    }
+
+    pub fn union(&self, other: &BitArray) -> Option<BitArray> {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &BitArray) -> Option<BitArray> {
+        self.combine(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: &BitArray) -> Option<BitArray> {
+        self.combine(other, |a, b| a & !b)
+    }
+}
+
+impl Display for BitArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.size_y {
+            for x in 0..self.size_x {
+                if self.check_bit(x, y) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitArray;
+
+    #[test]
+    fn test_bounds_checks() {
+        let mut bits = BitArray::new(3, 2);
+        assert_eq!(bits.set_bit(2, 0), Some(false));
+        assert_eq!(bits.set_bit(2, 0), Some(true));
+        // used to alias into (0, 1)
+        assert_eq!(bits.set_bit(3, 0), None);
+        assert!(!bits.check_bit(0, 1));
+        assert!(!bits.check_bit(3, 0));
+        assert_eq!(bits.toggle(0, 1), Some(false));
+        assert_eq!(bits.clear_bit(2, 0), Some(true));
+        assert_eq!(bits.clear_bit(0, 2), None);
+        assert_eq!(bits.to_string(), "...\n#..\n");
+        assert_eq!(bits.sum_bits(), 1);
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let mut a = BitArray::new(2, 2);
+        let mut b = BitArray::new(2, 2);
+        a.set_bit(0, 0);
+        a.set_bit(1, 0);
+        b.set_bit(1, 0);
+        b.set_bit(1, 1);
+        assert_eq!(a.union(&b).unwrap().to_string(), "##\n.#\n");
+        assert_eq!(a.intersection(&b).unwrap().to_string(), ".#\n..\n");
+        assert_eq!(a.difference(&b).unwrap().to_string(), "#.\n..\n");
+        assert_eq!(a.union(&BitArray::new(2, 3)), None);
+    }
+
+    #[test]
+    fn test_counts_and_iter() {
+        // rows straddle word boundaries
+        let mut bits = BitArray::new(50, 4);
+        for (x, y) in [(0, 0), (49, 0), (10, 1), (49, 1), (0, 2), (20, 3)].iter() {
+            bits.set_bit(*x, *y);
+        }
+        let rows: Vec<_> = (0..4).map(|y| bits.row_count(y).unwrap()).collect();
+        assert_eq!(rows, vec![2, 2, 1, 1]);
+        assert_eq!(bits.row_count(4), None);
+        assert_eq!(bits.column_count(49), Some(2));
+        assert_eq!(bits.column_count(0), Some(2));
+        assert_eq!(bits.column_count(50), None);
+        assert_eq!(
+            bits.iter().collect::<Vec<_>>(),
+            vec![(0, 0), (49, 0), (10, 1), (49, 1), (0, 2), (20, 3)]
+        );
+    }
+}
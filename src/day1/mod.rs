@@ -0,0 +1,457 @@
+use crate::error::{self, AocError};
+use crate::solution::Solution;
+use nom::branch::alt;
+use nom::character::complete::{line_ending, space0};
+use nom::combinator::{all_consuming, cut, eof};
+use nom::multi::{many0, many1, separated_list0};
+use nom::sequence::{delimited, pair, terminated};
+use nom::IResult;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+
+pub mod stats;
+
+/*
+ * Fields are compared in order, so ants sort by total calories, then by number
+ * Distinct elves never share a number, so `calories` never decides
+ */
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct Ant {
+    total: u64,
+    number: usize,
+    calories: Vec<u64>,
+}
+
+impl Display for Ant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} - {:?}", self.number, self.calories)
+    }
+}
+
+impl Ant {
+    // None when the total does not fit in u64
+    pub fn new(number: usize, calories: Vec<u64>) -> Option<Self> {
+        let total = total_calories(calories.iter().copied())?;
+        Some(Ant {
+            total,
+            number,
+            calories,
+        })
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn calories(&self) -> &[u64] {
+        &self.calories
+    }
+
+    // computed once in `new`
+    pub fn sum_calories(&self) -> u64 {
+        self.total
+    }
+}
+
+// None when the sum does not fit in u64
+pub fn total_calories<I: IntoIterator<Item = u64>>(calories: I) -> Option<u64> {
+    calories
+        .into_iter()
+        .try_fold(0u64, |total, item| total.checked_add(item))
+}
+
+// calorie count and where it starts, to report overflowing totals
+type Located<'a> = (&'a [u8], u64);
+
+// one calorie count, blanks around it are fine
+fn parse_item(i: &[u8]) -> IResult<&[u8], Located<'_>> {
+    let (start, _) = space0(i)?;
+    let (input, calories) = terminated(nom::character::complete::u64, space0)(start)?;
+    Ok((input, (start, calories)))
+}
+
+// only blanks, `\r\n` is matched by line_ending
+fn parse_blank_line(i: &[u8]) -> IResult<&[u8], ()> {
+    let (input, _) = terminated(space0, line_ending)(i)?;
+    Ok((input, ()))
+}
+
+// nothing but the count may be on its line, so the error points at the junk
+fn parse_group(i: &[u8]) -> IResult<&[u8], Vec<Located<'_>>> {
+    many1(terminated(parse_item, cut(alt((line_ending, eof)))))(i)
+}
+
+/*
+ * Groups of calorie counts, any run of blank lines separates ants
+ */
+pub fn parse_input(input: &[u8]) -> IResult<&[u8], Vec<Vec<u64>>> {
+    let (input, groups) = parse_located(input)?;
+    let groups = groups
+        .into_iter()
+        .map(|group| group.into_iter().map(|(_, calories)| calories).collect())
+        .collect();
+    Ok((input, groups))
+}
+
+// items keep their position in the input
+fn parse_located(input: &[u8]) -> IResult<&[u8], Vec<Vec<Located<'_>>>> {
+    all_consuming(delimited(
+        many0(parse_blank_line),
+        separated_list0(many1(parse_blank_line), parse_group),
+        pair(many0(parse_blank_line), space0),
+    ))(input)
+}
+
+pub fn parse_ants(input: &[u8]) -> Result<Vec<Ant>, AocError> {
+    let groups = error::finish(input, parse_located(input))?;
+    groups
+        .into_iter()
+        .enumerate()
+        .map(|(number, group)| {
+            let mut total: u64 = 0;
+            for &(start, calories) in &group {
+                total = total
+                    .checked_add(calories)
+                    .ok_or_else(|| AocError::parse_at(input, start, "calorie total overflows"))?;
+            }
+            Ok(Ant {
+                total,
+                number,
+                calories: group.into_iter().map(|(_, calories)| calories).collect(),
+            })
+        })
+        .collect()
+}
+
+/*
+ * Running total of one elf, produced by `elf_totals` without keeping its items
+ */
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct ElfTotal {
+    pub calories: u64,
+    pub number: usize,
+}
+
+pub struct ElfTotals<R> {
+    reader: R,
+    line: String,
+    line_idx: usize,
+    number: usize,
+    done: bool,
+}
+
+/*
+ * Stream per-elf totals, reading one line at a time into a reused buffer
+ * Same rules as `parse_ants`: blanks are ignored, any run of blank lines ends an elf
+ */
+pub fn elf_totals<R: BufRead>(reader: R) -> ElfTotals<R> {
+    ElfTotals {
+        reader,
+        line: String::new(),
+        line_idx: 0,
+        number: 0,
+        done: false,
+    }
+}
+
+impl<R: BufRead> ElfTotals<R> {
+    fn finish_elf(&mut self, calories: u64) -> ElfTotal {
+        let total = ElfTotal {
+            calories,
+            number: self.number,
+        };
+        self.number += 1;
+        total
+    }
+}
+
+impl<R: BufRead> Iterator for ElfTotals<R> {
+    type Item = Result<ElfTotal, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut calories: Option<u64> = None;
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => {
+                    self.done = true;
+                    return calories.map(|calories| Ok(self.finish_elf(calories)));
+                }
+                Ok(_) => self.line_idx += 1,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err.into()));
+                }
+            }
+
+            let item = self.line.trim();
+            if item.is_empty() {
+                match calories {
+                    Some(calories) => return Some(Ok(self.finish_elf(calories))),
+                    None => continue,
+                }
+            }
+            let result = item
+                .parse::<u64>()
+                .map_err(|err| format!("invalid calories: {}", err))
+                .and_then(|item| {
+                    item.checked_add(calories.unwrap_or(0))
+                        .ok_or_else(|| String::from("calorie total overflows"))
+                });
+            match result {
+                Ok(total) => calories = Some(total),
+                Err(message) => {
+                    self.done = true;
+                    let column = self.line.len() - self.line.trim_start().len() + 1;
+                    let line = self.line.trim_end_matches('\n');
+                    return Some(Err(AocError::parse(self.line_idx, column, line, message)));
+                }
+            }
+        }
+    }
+}
+
+/*
+ * Keeps the `k` largest items pushed so far, the smallest of them on top of the heap
+ */
+pub struct TopK<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        TopK {
+            k,
+            // `k` comes from the user, the heap grows with what is pushed
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(item));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if item > smallest.0 {
+                *smallest = Reverse(item);
+            }
+        }
+    }
+
+    // largest first
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(item)| item)
+            .collect()
+    }
+}
+
+pub fn top_k<T: Ord, I: IntoIterator<Item = T>>(items: I, k: usize) -> Vec<T> {
+    let mut top = TopK::new(k);
+    items.into_iter().for_each(|item| top.push(item));
+    top.into_sorted_vec()
+}
+
+// top `k` elves of a whole calorie log, largest first
+pub fn top_k_totals<R: BufRead>(reader: R, k: usize) -> Result<Vec<ElfTotal>, AocError> {
+    let mut top = TopK::new(k);
+    for total in elf_totals(reader) {
+        top.push(total?);
+    }
+    Ok(top.into_sorted_vec())
+}
+
+/*
+ * Puzzle 2: sum the first `n` ants with the most calories
+ */
+pub fn sum_of_top_ants(ants: &[Ant], n: usize) -> Result<u64, AocError> {
+    total_calories(top_k(ants, n).iter().map(|ant| ant.sum_calories()))
+        .ok_or_else(|| AocError::semantic(format!("sum of the top {} ants overflows", n)))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Rank {
+    // 1-based, tied elves share a position and the next one is skipped
+    pub position: usize,
+    pub number: usize,
+    pub calories: u64,
+}
+
+/*
+ * Standard competition ranking, most calories first, tied elves listed by number
+ */
+pub fn ranking(ants: &[Ant]) -> Vec<Rank> {
+    let mut ants: Vec<&Ant> = ants.iter().collect();
+    ants.sort_by_key(|ant| (Reverse(ant.total), ant.number));
+
+    let mut ranks: Vec<Rank> = Vec::with_capacity(ants.len());
+    for (idx, ant) in ants.iter().enumerate() {
+        let position = match ranks.last() {
+            Some(previous) if previous.calories == ant.total => previous.position,
+            _ => idx + 1,
+        };
+        ranks.push(Rank {
+            position,
+            number: ant.number,
+            calories: ant.total,
+        });
+    }
+    ranks
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<Ant>;
+
+    fn parse(input: &[u8]) -> Result<Self::Input, AocError> {
+        parse_ants(input)
+    }
+
+    fn part1(ants: &Self::Input) -> Result<String, AocError> {
+        let max_ant = ants
+            .iter()
+            .max()
+            .ok_or_else(|| AocError::semantic("no ants in input"))?;
+        Ok(max_ant.sum_calories().to_string())
+    }
+
+    fn part2(ants: &Self::Input) -> Result<String, AocError> {
+        Ok(sum_of_top_ants(ants, 3)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        elf_totals, parse_ants, ranking, sum_of_top_ants, top_k, top_k_totals, Ant, ElfTotal, Rank,
+    };
+    use crate::error::AocError;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_top_k() {
+        assert_eq!(top_k(vec![5, 1, 9, 3, 7], 3), vec![9, 7, 5]);
+        assert_eq!(top_k(vec![2, 1], 5), vec![2, 1]);
+        assert_eq!(top_k(vec![2, 1], 0), Vec::<i32>::new());
+        assert_eq!(top_k(vec![2, 1], usize::MAX), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_elf_totals() {
+        let input = include_str!("../../examples/day1/sample.txt");
+        let totals: Vec<u64> = elf_totals(input.as_bytes())
+            .map(|total| total.unwrap().calories)
+            .collect();
+        assert_eq!(totals, vec![6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(
+            top_k_totals(input.as_bytes(), 2).unwrap(),
+            vec![
+                ElfTotal {
+                    calories: 24000,
+                    number: 3
+                },
+                ElfTotal {
+                    calories: 11000,
+                    number: 2
+                }
+            ]
+        );
+
+        let messy = " 1\t\r\n2 \r\n\r\n\n \n3\r\n\n";
+        let totals: Vec<u64> = elf_totals(messy.as_bytes())
+            .map(|total| total.unwrap().calories)
+            .collect();
+        assert_eq!(totals, vec![3, 3]);
+
+        let overflow = "18446744073709551615\n1\n";
+        assert!(matches!(
+            top_k_totals(overflow.as_bytes(), 3),
+            Err(AocError::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_ants() {
+        // CRLF, blanks around numbers and runs of blank lines
+        let input = "\r\n1000 \r\n\t2000\r\n\r\n  \r\n\r\n5000000000\r\n\r\n";
+        let ants = parse_ants(input.as_bytes()).unwrap();
+        let sums: Vec<u64> = ants.iter().map(|ant| ant.sum_calories()).collect();
+        assert_eq!(sums, vec![3000, 5_000_000_000]);
+        assert_eq!(ants[1].number(), 1);
+
+        match parse_ants("100\n200\n\n3x0\n".as_bytes()) {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (4, 2)),
+            _ => panic!("expected parse error"),
+        }
+        assert!(matches!(
+            parse_ants("1\n99999999999999999999\n".as_bytes()),
+            Err(AocError::Parse { line: 2, .. })
+        ));
+        // each item fits, the total does not
+        assert!(matches!(
+            parse_ants("5\n\n18446744073709551615\n 1\n".as_bytes()),
+            Err(AocError::Parse {
+                line: 4,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(Ant::new(0, vec![u64::MAX, 1]).is_none());
+        let ants = parse_ants("18446744073709551615\n\n1\n".as_bytes()).unwrap();
+        assert!(sum_of_top_ants(&ants, 1).is_ok());
+        assert!(matches!(
+            sum_of_top_ants(&ants, 2),
+            Err(AocError::Semantic(_))
+        ));
+    }
+
+    #[test]
+    fn test_ant_order() {
+        let first = Ant::new(0, vec![5, 5]).unwrap();
+        let second = Ant::new(1, vec![10]).unwrap();
+        let third = Ant::new(2, vec![3]).unwrap();
+        // equal totals used to compare Equal while being unequal
+        assert_ne!(first, second);
+        assert!(first < second);
+        assert_eq!(
+            vec![&first, &second, &third].into_iter().max(),
+            Some(&second)
+        );
+
+        let set: BTreeSet<Ant> = vec![second.clone(), first.clone(), third.clone()]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 3);
+        assert_eq!(
+            set.iter().map(|ant| ant.number()).collect::<Vec<_>>(),
+            vec![2, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_ranking() {
+        let ants = parse_ants("10\n\n30\n\n10\n20\n\n5\n".as_bytes()).unwrap();
+        let ranks: Vec<(usize, usize)> = ranking(&ants)
+            .iter()
+            .map(|rank| (rank.position, rank.number))
+            .collect();
+        assert_eq!(ranks, vec![(1, 1), (1, 2), (3, 0), (4, 3)]);
+        assert_eq!(
+            ranking(&ants)[2],
+            Rank {
+                position: 3,
+                number: 0,
+                calories: 10
+            }
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use super::Ant;
+use crate::error::AocError;
+use serde::Serialize;
+use std::fmt::{self, Display};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct ElfSummary {
+    pub number: usize,
+    pub items: usize,
+    pub calories: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Percentile {
+    pub percentile: u8,
+    pub calories: u64,
+}
+
+// elves with `from <= calories < to`, `to` saturates at u64::MAX and that bucket holds it too
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Bucket {
+    pub from: u64,
+    pub to: u64,
+    pub elves: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct Report {
+    pub elves: Vec<ElfSummary>,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<Percentile>,
+    pub histogram: Vec<Bucket>,
+    pub max: u64,
+    // every elf carrying `max`, more than one when tied
+    pub max_elves: Vec<usize>,
+}
+
+/*
+ * Nearest-rank percentile of sorted totals
+ */
+fn percentile(sorted: &[u64], percentile: u8) -> u64 {
+    let rank = (percentile as usize * sorted.len()).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+/*
+ * Equal-width buckets from the smallest to the largest total
+ * Computed in u128, totals may span the whole u64 range
+ */
+fn histogram(sorted: &[u64], buckets: usize) -> Vec<Bucket> {
+    let (min, max) = (sorted[0] as u128, sorted[sorted.len() - 1] as u128);
+    let width = (max - min) / buckets as u128 + 1;
+    (0..buckets as u128)
+        .map(|idx| min + idx * width)
+        .take_while(|&from| from <= max)
+        .map(|from| {
+            let to = from + width;
+            Bucket {
+                from: from as u64,
+                to: to.min(u64::MAX as u128) as u64,
+                elves: sorted
+                    .iter()
+                    .filter(|&&c| from <= c as u128 && (c as u128) < to)
+                    .count(),
+            }
+        })
+        .collect()
+}
+
+pub fn report(ants: &[Ant], percentiles: &[u8], buckets: usize) -> Result<Report, AocError> {
+    if ants.is_empty() {
+        return Err(AocError::semantic("no ants in input"));
+    }
+    if buckets == 0 {
+        return Err(AocError::semantic("histogram needs at least one bucket"));
+    }
+    if let Some(p) = percentiles.iter().find(|&&p| p > 100) {
+        return Err(AocError::semantic(format!("percentile {} is over 100", p)));
+    }
+
+    let elves: Vec<ElfSummary> = ants
+        .iter()
+        .map(|ant| ElfSummary {
+            number: ant.number(),
+            items: ant.calories().len(),
+            calories: ant.sum_calories(),
+        })
+        .collect();
+    let mut sorted: Vec<u64> = elves.iter().map(|elf| elf.calories).collect();
+    sorted.sort_unstable();
+
+    let mid = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] as f64 + sorted[mid] as f64) / 2.0
+    } else {
+        sorted[mid] as f64
+    };
+    let max = sorted[sorted.len() - 1];
+
+    Ok(Report {
+        mean: sorted.iter().map(|&c| c as f64).sum::<f64>() / sorted.len() as f64,
+        median,
+        percentiles: percentiles
+            .iter()
+            .map(|&p| Percentile {
+                percentile: p,
+                calories: percentile(&sorted, p),
+            })
+            .collect(),
+        histogram: histogram(&sorted, buckets),
+        max,
+        max_elves: elves
+            .iter()
+            .filter(|elf| elf.calories == max)
+            .map(|elf| elf.number)
+            .collect(),
+        elves,
+    })
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report is always serializable")
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "elves:  {}", self.elves.len())?;
+        writeln!(f, "mean:   {:.1}", self.mean)?;
+        writeln!(f, "median: {:.1}", self.median)?;
+        for p in &self.percentiles {
+            writeln!(f, "p{:<5} {}", p.percentile, p.calories)?;
+        }
+        let max_elves: Vec<String> = self.max_elves.iter().map(|n| n.to_string()).collect();
+        writeln!(f, "max:    {} (elf {})", self.max, max_elves.join(", "))?;
+
+        writeln!(f, "\nhistogram:")?;
+        let widest = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        for bucket in &self.histogram {
+            // bars are scaled to at most 40 chars
+            let bar = (bucket.elves * 40).div_ceil(widest.max(1));
+            writeln!(
+                f,
+                "{:>8} - {:<8} {:>5} {}",
+                bucket.from,
+                bucket.to,
+                bucket.elves,
+                "#".repeat(bar)
+            )?;
+        }
+
+        writeln!(f, "\n elf  items  calories")?;
+        for elf in &self.elves {
+            writeln!(
+                f,
+                "{:>4}  {:>5}  {:>8}",
+                elf.number, elf.items, elf.calories
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{report, Bucket, Percentile};
+    use crate::day1::parse_ants;
+
+    #[test]
+    fn test_report() {
+        let ants = parse_ants(
+            "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n\n6000\n"
+                .as_bytes(),
+        )
+        .unwrap();
+        let report = report(&ants, &[0, 50, 90, 100], 2).unwrap();
+        assert_eq!(report.elves[0].items, 3);
+        assert_eq!(report.mean, 61000.0 / 6.0);
+        assert_eq!(report.median, 8000.0);
+        assert_eq!(
+            report.percentiles,
+            vec![
+                Percentile {
+                    percentile: 0,
+                    calories: 4000
+                },
+                Percentile {
+                    percentile: 50,
+                    calories: 6000
+                },
+                Percentile {
+                    percentile: 90,
+                    calories: 24000
+                },
+                Percentile {
+                    percentile: 100,
+                    calories: 24000
+                }
+            ]
+        );
+        assert_eq!(
+            report.histogram,
+            vec![
+                Bucket {
+                    from: 4000,
+                    to: 14001,
+                    elves: 5
+                },
+                Bucket {
+                    from: 14001,
+                    to: 24002,
+                    elves: 1
+                }
+            ]
+        );
+        assert_eq!((report.max, report.max_elves.clone()), (24000, vec![3]));
+        assert!(report.to_json().contains("\"max_elves\": [\n    3\n  ]"));
+    }
+
+    #[test]
+    fn test_histogram_full_range() {
+        let ants = parse_ants("0\n\n18446744073709551615\n".as_bytes()).unwrap();
+        let single = report(&ants, &[], 1).unwrap();
+        assert_eq!(
+            single.histogram,
+            vec![Bucket {
+                from: 0,
+                to: u64::MAX,
+                elves: 2
+            }]
+        );
+        let halves = report(&ants, &[], 2).unwrap();
+        assert_eq!(halves.histogram[1].elves, 1);
+    }
+
+    #[test]
+    fn test_max_ties() {
+        let ants = parse_ants("5\n\n7\n\n3\n4\n".as_bytes()).unwrap();
+        let report = report(&ants, &[], 1).unwrap();
+        assert_eq!(report.max_elves, vec![1, 2]);
+        assert!(report.to_string().contains("max:    7 (elf 1, 2)"));
+    }
+}
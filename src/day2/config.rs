@@ -0,0 +1,202 @@
+use super::rules::Outcome;
+use super::PRSMove;
+use crate::error::AocError;
+use serde::Deserialize;
+use std::path::Path;
+
+/*
+ * Letters of the strategy guide, in Rock, Paper, Scissors order
+ */
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Encoding {
+    pub opponent: [char; 3],
+    pub player: [char; 3],
+}
+
+impl Default for Encoding {
+    fn default() -> Self {
+        Encoding {
+            opponent: ['A', 'B', 'C'],
+            player: ['X', 'Y', 'Z'],
+        }
+    }
+}
+
+impl Encoding {
+    pub fn opponent_move(&self, letter: char) -> Option<PRSMove> {
+        Self::decode(&self.opponent, letter)
+    }
+
+    pub fn player_move(&self, letter: char) -> Option<PRSMove> {
+        Self::decode(&self.player, letter)
+    }
+
+    fn decode(letters: &[char; 3], letter: char) -> Option<PRSMove> {
+        letters
+            .iter()
+            .position(|&l| l == letter)
+            .map(PRSMove::from_index)
+    }
+}
+
+/*
+ * Points for the shape played (Rock, Paper, Scissors) and for the outcome (Lose, Draw, Win)
+ */
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Scoring {
+    pub shape: [u32; 3],
+    pub outcome: [u32; 3],
+}
+
+impl Default for Scoring {
+    fn default() -> Self {
+        Scoring {
+            shape: [1, 2, 3],
+            outcome: [0, 3, 6],
+        }
+    }
+}
+
+impl Scoring {
+    pub fn shape_points(&self, shape: PRSMove) -> u32 {
+        self.shape[shape.index()]
+    }
+
+    pub fn outcome_points(&self, outcome: Outcome) -> u32 {
+        self.outcome[outcome as usize]
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Config {
+    pub encoding: Encoding,
+    pub scoring: Scoring,
+}
+
+/*
+ * Config as written in a file or given on the command line, every key optional:
+ *
+ *   opponent = "ABC"
+ *   player = "XYZ"
+ *   shape_scores = [1, 2, 3]
+ *   outcome_scores = [0, 3, 6]
+ */
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PartialConfig {
+    pub opponent: Option<String>,
+    pub player: Option<String>,
+    pub shape_scores: Option<Vec<u32>>,
+    pub outcome_scores: Option<Vec<u32>>,
+}
+
+// the guide is parsed byte by byte, so letters must be single printable ASCII bytes
+fn letters(key: &str, value: &str) -> Result<[char; 3], AocError> {
+    if let Some(letter) = value.chars().find(|c| !c.is_ascii_graphic()) {
+        return Err(AocError::semantic(format!(
+            "`{}` must be printable ASCII letters, got {:?}",
+            key, letter
+        )));
+    }
+    let chars: Vec<char> = value.chars().collect();
+    match chars[..] {
+        [a, b, c] if a != b && b != c && a != c => Ok([a, b, c]),
+        _ => Err(AocError::semantic(format!(
+            "`{}` must be 3 distinct letters, got {:?}",
+            key, value
+        ))),
+    }
+}
+
+fn scores(key: &str, value: &[u32]) -> Result<[u32; 3], AocError> {
+    match value {
+        [a, b, c] => Ok([*a, *b, *c]),
+        _ => Err(AocError::semantic(format!(
+            "`{}` must have 3 scores, got {}",
+            key,
+            value.len()
+        ))),
+    }
+}
+
+impl PartialConfig {
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        toml::from_str(input).map_err(|err| AocError::from_toml(input, err))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    // keys set in `self` win over `fallback`
+    pub fn or(self, fallback: PartialConfig) -> PartialConfig {
+        PartialConfig {
+            opponent: self.opponent.or(fallback.opponent),
+            player: self.player.or(fallback.player),
+            shape_scores: self.shape_scores.or(fallback.shape_scores),
+            outcome_scores: self.outcome_scores.or(fallback.outcome_scores),
+        }
+    }
+
+    // missing keys take the puzzle's values
+    pub fn build(&self) -> Result<Config, AocError> {
+        let mut config = Config::default();
+        if let Some(opponent) = &self.opponent {
+            config.encoding.opponent = letters("opponent", opponent)?;
+        }
+        if let Some(player) = &self.player {
+            config.encoding.player = letters("player", player)?;
+        }
+        if let Some(shape) = &self.shape_scores {
+            config.scoring.shape = scores("shape_scores", shape)?;
+        }
+        if let Some(outcome) = &self.outcome_scores {
+            config.scoring.outcome = scores("outcome_scores", outcome)?;
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, PartialConfig};
+    use crate::error::AocError;
+
+    #[test]
+    fn test_parse_config() {
+        let file = PartialConfig::parse("player = \"UVW\"\nshape_scores = [3, 2, 1]\n").unwrap();
+        let flags = PartialConfig {
+            player: Some(String::from("RST")),
+            ..PartialConfig::default()
+        };
+        let config = flags.or(file).build().unwrap();
+        assert_eq!(config.encoding.player, ['R', 'S', 'T']);
+        assert_eq!(config.encoding.opponent, ['A', 'B', 'C']);
+        assert_eq!(config.scoring.shape, [3, 2, 1]);
+        assert_eq!(config.scoring.outcome, [0, 3, 6]);
+        assert_eq!(PartialConfig::default().build().unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(matches!(
+            PartialConfig::parse("players = \"XYZ\"\n"),
+            Err(AocError::Parse { line: 1, .. })
+        ));
+        let duplicate = PartialConfig {
+            opponent: Some(String::from("AAB")),
+            ..PartialConfig::default()
+        };
+        assert!(matches!(duplicate.build(), Err(AocError::Semantic(_))));
+        let unicode = PartialConfig {
+            player: Some(String::from("XYé")),
+            ..PartialConfig::default()
+        };
+        assert!(matches!(unicode.build(), Err(AocError::Semantic(_))));
+        let short = PartialConfig {
+            outcome_scores: Some(vec![0, 3]),
+            ..PartialConfig::default()
+        };
+        assert!(matches!(short.build(), Err(AocError::Semantic(_))));
+    }
+}
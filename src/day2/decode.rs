@@ -0,0 +1,172 @@
+use super::config::Scoring;
+use super::rules::Outcome;
+use super::{total_score, PRSMove, PRSRound};
+use crate::error::AocError;
+use std::fmt::{self, Display};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Meaning {
+    Moves,
+    Outcomes,
+}
+
+/*
+ * One way to read the second column: its three letters, in order, stand for
+ * PRSMove::ALL[permutation[i]] or Outcome::ALL[permutation[i]]
+ */
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Interpretation {
+    pub meaning: Meaning,
+    pub permutation: [usize; 3],
+    pub score: u64,
+}
+
+impl Interpretation {
+    // the puzzle's two readings, part 1 and part 2
+    pub fn is_canonical(&self) -> bool {
+        self.permutation == [0, 1, 2]
+    }
+
+    fn name(&self, letter: usize) -> String {
+        let idx = self.permutation[letter];
+        match self.meaning {
+            Meaning::Moves => format!("{:?}", PRSMove::ALL[idx]),
+            Meaning::Outcomes => format!("{:?}", Outcome::ALL[idx]),
+        }
+    }
+}
+
+pub const PERMUTATIONS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [2, 1, 0],
+];
+
+/*
+ * Score the whole guide with the second column remapped by `permutation`,
+ * moves are scored like part 1 and outcomes like part 2 (`score_fixed`)
+ */
+pub fn score_as(
+    game: &[PRSRound],
+    meaning: Meaning,
+    permutation: [usize; 3],
+    scoring: &Scoring,
+) -> Result<u64, AocError> {
+    total_score(game.iter().map(|round| {
+        let remapped = PRSRound {
+            opponent: round.opponent,
+            player: PRSMove::from_index(permutation[round.player.index()]),
+        };
+        match meaning {
+            Meaning::Moves => remapped.score_with(scoring),
+            Meaning::Outcomes => remapped.score_fixed_with(scoring),
+        }
+    }))
+}
+
+pub struct Report {
+    // letters of the second column, for display
+    pub letters: [char; 3],
+    // best first
+    pub interpretations: Vec<Interpretation>,
+}
+
+pub fn decode(
+    game: &[PRSRound],
+    letters: [char; 3],
+    scoring: &Scoring,
+) -> Result<Report, AocError> {
+    let mut interpretations = [Meaning::Moves, Meaning::Outcomes]
+        .iter()
+        .flat_map(|&meaning| {
+            PERMUTATIONS.iter().map(move |&permutation| {
+                Ok(Interpretation {
+                    meaning,
+                    permutation,
+                    score: score_as(game, meaning, permutation, scoring)?,
+                })
+            })
+        })
+        .collect::<Result<Vec<Interpretation>, AocError>>()?;
+    interpretations.sort_by_key(|interpretation| std::cmp::Reverse(interpretation.score));
+    Ok(Report {
+        letters,
+        interpretations,
+    })
+}
+
+impl Report {
+    pub fn best(&self) -> &Interpretation {
+        &self.interpretations[0]
+    }
+
+    pub fn worst(&self) -> &Interpretation {
+        &self.interpretations[self.interpretations.len() - 1]
+    }
+
+    pub fn canonical(&self, meaning: Meaning) -> &Interpretation {
+        self.interpretations
+            .iter()
+            .find(|i| i.meaning == meaning && i.is_canonical())
+            .unwrap()
+    }
+
+    fn describe(&self, interpretation: &Interpretation) -> String {
+        let names: Vec<String> = self
+            .letters
+            .iter()
+            .enumerate()
+            .map(|(letter_idx, letter)| format!("{}={}", letter, interpretation.name(letter_idx)))
+            .collect();
+        format!("{:>7}  {}", interpretation.score, names.join(" "))
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "best:     {}", self.describe(self.best()))?;
+        writeln!(f, "worst:    {}", self.describe(self.worst()))?;
+        writeln!(
+            f,
+            "part 1:   {}",
+            self.describe(self.canonical(Meaning::Moves))
+        )?;
+        writeln!(
+            f,
+            "part 2:   {}",
+            self.describe(self.canonical(Meaning::Outcomes))
+        )?;
+        writeln!(f, "\nall encodings:")?;
+        for interpretation in &self.interpretations {
+            writeln!(f, "          {}", self.describe(interpretation))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, Meaning};
+    use crate::day2::config::Scoring;
+    use crate::day2::parse_game;
+
+    #[test]
+    fn test_decode_sample() {
+        let (_, game) = parse_game(b"A Y\nB X\nC Z\n").unwrap();
+        let report = decode(&game, ['X', 'Y', 'Z'], &Scoring::default()).unwrap();
+        assert_eq!(report.interpretations.len(), 12);
+        assert_eq!(report.canonical(Meaning::Moves).score, 15);
+        assert_eq!(report.canonical(Meaning::Outcomes).score, 12);
+        // X=Scissors Y=Paper Z=Rock wins all three rounds
+        assert_eq!(report.best().score, 24);
+        assert_eq!(report.best().meaning, Meaning::Moves);
+        assert_eq!(report.best().permutation, [2, 1, 0]);
+        assert!(report.worst().score <= 12);
+        assert!(report
+            .to_string()
+            .contains("part 1:        15  X=Rock Y=Paper Z=Scissors"));
+    }
+}
@@ -0,0 +1,93 @@
+use super::config::Config;
+use super::{score_strategy_fixed_with, score_strategy_with, PRSRound};
+use crate::error::AocError;
+use std::fmt::{self, Display};
+
+/*
+ * Round-by-round breakdown of both parts, with running totals
+ * Part 2 columns show the move `score_fixed` picks for the wanted outcome
+ */
+pub struct Explanation<'a> {
+    game: &'a [PRSRound],
+    config: &'a Config,
+}
+
+impl<'a> Explanation<'a> {
+    // both final totals are checked here, so the running ones fit in u64 too
+    pub fn new(game: &'a [PRSRound], config: &'a Config) -> Result<Self, AocError> {
+        score_strategy_with(game, &config.scoring)?;
+        score_strategy_fixed_with(game, &config.scoring)?;
+        Ok(Explanation { game, config })
+    }
+
+    fn write_part(&self, f: &mut fmt::Formatter, round: &PRSRound, total: &mut u64) -> fmt::Result {
+        let scoring = &self.config.scoring;
+        let shape = scoring.shape_points(round.player);
+        let outcome = scoring.outcome_points(round.outcome());
+        *total += round.score_with(scoring);
+        write!(
+            f,
+            "{:<9} {:<6} {:>5} {:>7} {:>7}",
+            format!("{:?}", round.player),
+            format!("{:?}", round.outcome()),
+            shape,
+            outcome,
+            total
+        )
+    }
+}
+
+impl<'a> Display for Explanation<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let part_header = format!(
+            "{:<9} {:<6} {:>5} {:>7} {:>7}",
+            "player", "result", "shape", "outcome", "total"
+        );
+        writeln!(
+            f,
+            "{:>5}  {:<4} {:<9} | part 1: {} | part 2: {}",
+            "round", "line", "opponent", part_header, part_header
+        )?;
+
+        let encoding = &self.config.encoding;
+        let (mut total, mut total_fixed) = (0, 0);
+        for (idx, round) in self.game.iter().enumerate() {
+            write!(
+                f,
+                "{:>5}  {} {}  {:<9} | part 1: ",
+                idx + 1,
+                encoding.opponent[round.opponent.index()],
+                encoding.player[round.player.index()],
+                format!("{:?}", round.opponent)
+            )?;
+            self.write_part(f, round, &mut total)?;
+            write!(f, " | part 2: ")?;
+            self.write_part(f, &round.fixed(), &mut total_fixed)?;
+            writeln!(f)?;
+        }
+        writeln!(f, "part 1 total: {}", total)?;
+        writeln!(f, "part 2 total: {}", total_fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Explanation;
+    use crate::day2::config::Config;
+    use crate::day2::parse_game;
+
+    #[test]
+    fn test_explain_sample() {
+        let (_, game) = parse_game(b"A Y\nB X\nC Z\n").unwrap();
+        let config = Config::default();
+        let text = Explanation::new(&game, &config).unwrap().to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[1],
+            "    1  A Y  Rock      | part 1: Paper     Win        2       6       8 | part 2: Rock      Draw       1       3       4"
+        );
+        assert_eq!(lines[4], "part 1 total: 15");
+        assert_eq!(lines[5], "part 2 total: 12");
+    }
+}
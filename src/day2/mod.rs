@@ -0,0 +1,293 @@
+use crate::error::{self, AocError};
+use crate::solution::Solution;
+use config::{Encoding, Scoring};
+use nom::bytes::complete::tag;
+use nom::character::complete::{anychar, line_ending};
+use nom::combinator::{eof, map_opt, opt};
+use nom::multi::many0;
+use nom::sequence::{terminated, tuple};
+use nom::IResult;
+use rules::{Outcome, Rules};
+
+pub mod config;
+pub mod decode;
+pub mod explain;
+pub mod rules;
+pub mod tournament;
+
+// game types
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum PRSMove {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+// the puzzle's game, see `Rules::rock_paper_scissors`
+impl PRSMove {
+    pub const ALL: [PRSMove; 3] = [PRSMove::Rock, PRSMove::Paper, PRSMove::Scissors];
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn from_index(idx: usize) -> PRSMove {
+        PRSMove::ALL[idx]
+    }
+
+    pub fn beats(&self, other: &PRSMove) -> bool {
+        Rules::rock_paper_scissors().beats(self.index(), other.index())
+    }
+
+    // with three moves there is exactly one response for every outcome
+    pub fn response(&self, outcome: Outcome) -> PRSMove {
+        let response = Rules::rock_paper_scissors()
+            .responses(self.index(), outcome)
+            .next()
+            .unwrap();
+        PRSMove::from_index(response)
+    }
+
+    pub fn get_winning(&self) -> PRSMove {
+        self.response(Outcome::Win)
+    }
+
+    pub fn get_loosing(&self) -> PRSMove {
+        self.response(Outcome::Lose)
+    }
+
+    // points with the puzzle's scoring
+    pub fn score(&self) -> u32 {
+        Scoring::default().shape_points(*self)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PRSRound {
+    pub opponent: PRSMove,
+    pub player: PRSMove,
+}
+
+impl PRSRound {
+    pub fn outcome(&self) -> Outcome {
+        Rules::rock_paper_scissors().outcome(self.player.index(), self.opponent.index())
+    }
+
+    pub fn score(&self) -> u64 {
+        self.score_with(&Scoring::default())
+    }
+
+    // two u32 points always fit in u64
+    pub fn score_with(&self, scoring: &Scoring) -> u64 {
+        u64::from(scoring.outcome_points(self.outcome()))
+            + u64::from(scoring.shape_points(self.player))
+    }
+
+    // X, Y, Z mean we need to loose, draw, win
+    pub fn fixed(&self) -> PRSRound {
+        PRSRound {
+            opponent: self.opponent,
+            player: self.opponent.response(Outcome::ALL[self.player.index()]),
+        }
+    }
+
+    pub fn score_fixed(&self) -> u64 {
+        self.score_fixed_with(&Scoring::default())
+    }
+
+    pub fn score_fixed_with(&self, scoring: &Scoring) -> u64 {
+        self.fixed().score_with(scoring)
+    }
+}
+
+// parsing like there's no tomorrow
+pub fn parse_player_move(input: &[u8]) -> IResult<&[u8], PRSMove> {
+    parse_player_move_with(input, &Encoding::default())
+}
+
+pub fn parse_opponent_move(input: &[u8]) -> IResult<&[u8], PRSMove> {
+    parse_opponent_move_with(input, &Encoding::default())
+}
+
+pub fn parse_player_move_with<'a>(
+    input: &'a [u8],
+    encoding: &Encoding,
+) -> IResult<&'a [u8], PRSMove> {
+    map_opt(anychar, |letter| encoding.player_move(letter))(input)
+}
+
+pub fn parse_opponent_move_with<'a>(
+    input: &'a [u8],
+    encoding: &Encoding,
+) -> IResult<&'a [u8], PRSMove> {
+    map_opt(anychar, |letter| encoding.opponent_move(letter))(input)
+}
+
+pub fn parse_game_line(i: &[u8]) -> IResult<&[u8], PRSRound> {
+    parse_game_line_with(i, &Encoding::default())
+}
+
+pub fn parse_game_line_with<'a>(i: &'a [u8], encoding: &Encoding) -> IResult<&'a [u8], PRSRound> {
+    let (input, (opponent_move, _, player_move)) = tuple((
+        |i| parse_opponent_move_with(i, encoding),
+        tag(" "),
+        |i| parse_player_move_with(i, encoding),
+    ))(i)?;
+    Ok((
+        input,
+        PRSRound {
+            opponent: opponent_move,
+            player: player_move,
+        },
+    ))
+}
+
+pub fn parse_game(input: &[u8]) -> IResult<&[u8], Vec<PRSRound>> {
+    parse_game_with(input, &Encoding::default())
+}
+
+pub fn parse_game_with<'a>(
+    input: &'a [u8],
+    encoding: &Encoding,
+) -> IResult<&'a [u8], Vec<PRSRound>> {
+    let (input, game) = many0(terminated(
+        |i| parse_game_line_with(i, encoding),
+        opt(line_ending),
+    ))(input)?;
+    eof(input)?;
+    Ok((input, game))
+}
+
+// custom scores can be large enough to overflow even u64 over a long guide
+pub fn total_score<I: IntoIterator<Item = u64>>(scores: I) -> Result<u64, AocError> {
+    scores
+        .into_iter()
+        .try_fold(0u64, |total, score| total.checked_add(score))
+        .ok_or_else(|| AocError::semantic("score total overflows u64"))
+}
+
+pub fn score_strategy(game: &[PRSRound]) -> Result<u64, AocError> {
+    score_strategy_with(game, &Scoring::default())
+}
+
+pub fn score_strategy_fixed(game: &[PRSRound]) -> Result<u64, AocError> {
+    score_strategy_fixed_with(game, &Scoring::default())
+}
+
+pub fn score_strategy_with(game: &[PRSRound], scoring: &Scoring) -> Result<u64, AocError> {
+    total_score(game.iter().map(|round| round.score_with(scoring)))
+}
+
+pub fn score_strategy_fixed_with(game: &[PRSRound], scoring: &Scoring) -> Result<u64, AocError> {
+    total_score(game.iter().map(|round| round.score_fixed_with(scoring)))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<PRSRound>;
+
+    fn parse(input: &[u8]) -> Result<Self::Input, AocError> {
+        error::finish(input, parse_game(input))
+    }
+
+    fn part1(game: &Self::Input) -> Result<String, AocError> {
+        Ok(score_strategy(game)?.to_string())
+    }
+
+    fn part2(game: &Self::Input) -> Result<String, AocError> {
+        Ok(score_strategy_fixed(game)?.to_string())
+    }
+}
+
+#[test]
+fn test_parse_game_line() {
+    let input = b"A Z";
+    let expected = Ok((
+        &b""[..],
+        PRSRound {
+            opponent: PRSMove::Rock,
+            player: PRSMove::Scissors,
+        },
+    ));
+    assert_eq!(parse_game_line(input), expected);
+}
+
+#[test]
+fn test_parse_game() {
+    let input = r#"A Z
+A X"#;
+    let expected = Ok((
+        &b""[..],
+        vec![
+            PRSRound {
+                opponent: PRSMove::Rock,
+                player: PRSMove::Scissors,
+            },
+            PRSRound {
+                opponent: PRSMove::Rock,
+                player: PRSMove::Rock,
+            },
+        ],
+    ));
+    assert_eq!(parse_game(input.as_bytes()), expected);
+}
+
+#[test]
+fn test_parse_game_with() {
+    let encoding = config::PartialConfig {
+        opponent: Some(String::from("RPS")),
+        player: Some(String::from("rps")),
+        ..config::PartialConfig::default()
+    }
+    .build()
+    .unwrap()
+    .encoding;
+    let (_, game) = parse_game_with(b"R s\nS p\n", &encoding).unwrap();
+    assert_eq!(
+        game[1],
+        PRSRound {
+            opponent: PRSMove::Scissors,
+            player: PRSMove::Paper,
+        }
+    );
+    assert!(parse_game_with(b"A X", &encoding).is_err());
+
+    let scoring = Scoring {
+        shape: [10, 20, 30],
+        outcome: [0, 1, 2],
+    };
+    assert_eq!(score_strategy_with(&game, &scoring).unwrap(), 30 + 20);
+    assert_eq!(
+        score_strategy_fixed_with(&game, &scoring).unwrap(),
+        20 + 2 + 30 + 1
+    );
+}
+
+#[test]
+fn test_score_overflow() {
+    let scoring = Scoring {
+        shape: [u32::MAX, 1, 1],
+        outcome: [u32::MAX, u32::MAX, u32::MAX],
+    };
+    let round = PRSRound {
+        opponent: PRSMove::Rock,
+        player: PRSMove::Rock,
+    };
+    assert_eq!(round.score_with(&scoring), 2 * u64::from(u32::MAX));
+    assert!(total_score(vec![u64::MAX, 1]).is_err());
+    assert_eq!(total_score(vec![u64::MAX - 1, 1]).unwrap(), u64::MAX);
+}
+
+#[test]
+fn test_parse_game_failed() {
+    let junk = "junk";
+    let input = r#"A Z
+A Xjunk"#;
+    let expected = Err(nom::Err::Error(nom::error::Error::new(
+        junk.as_bytes(),
+        nom::error::ErrorKind::Eof,
+    )));
+    assert_eq!(parse_game(input.as_bytes()), expected);
+}
@@ -0,0 +1,176 @@
+use super::config::Scoring;
+use crate::error::AocError;
+use std::sync::OnceLock;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    // points with the puzzle's scoring
+    pub fn score(&self) -> u32 {
+        Scoring::default().outcome_points(*self)
+    }
+}
+
+/*
+ * Moves of a game and which move beats which, as data
+ * Moves are referred to by their index in `names`
+ */
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Rules {
+    names: Vec<String>,
+    // beats[a][b]: move a beats move b
+    beats: Vec<Vec<bool>>,
+}
+
+impl Rules {
+    /*
+     * `wins` lists (winner, loser) pairs, moves not related either way draw
+     */
+    pub fn new(names: Vec<String>, wins: &[(usize, usize)]) -> Result<Self, AocError> {
+        let size = names.len();
+        let mut beats = vec![vec![false; size]; size];
+        for &(winner, loser) in wins {
+            if winner >= size || loser >= size {
+                return Err(AocError::semantic(format!(
+                    "move {} or {} does not exist, there are only {} moves",
+                    winner, loser, size
+                )));
+            }
+            if winner == loser {
+                return Err(AocError::semantic(format!(
+                    "{} cannot beat itself",
+                    names[winner]
+                )));
+            }
+            if beats[loser][winner] {
+                return Err(AocError::semantic(format!(
+                    "{} and {} cannot beat each other",
+                    names[winner], names[loser]
+                )));
+            }
+            beats[winner][loser] = true;
+        }
+        Ok(Rules { names, beats })
+    }
+
+    /*
+     * Balanced game where every move beats the (N-1)/2 moves listed before it,
+     * wrapping around, e.g. Rock, Paper, Scissors or Rock, Spock, Paper, Lizard, Scissors
+     */
+    pub fn cyclic(names: &[&str]) -> Result<Self, AocError> {
+        let size = names.len();
+        if size.is_multiple_of(2) {
+            return Err(AocError::semantic(format!(
+                "a cyclic game needs an odd number of moves, got {}",
+                size
+            )));
+        }
+        let wins: Vec<(usize, usize)> = (0..size)
+            .flat_map(|winner| {
+                (1..=size / 2).map(move |back| (winner, (winner + size - back) % size))
+            })
+            .collect();
+        Rules::new(names.iter().map(|name| name.to_string()).collect(), &wins)
+    }
+
+    // the puzzle's game, move indices match `PRSMove`
+    pub fn rock_paper_scissors() -> &'static Rules {
+        static RULES: OnceLock<Rules> = OnceLock::new();
+        RULES.get_or_init(|| Rules::cyclic(&["Rock", "Paper", "Scissors"]).unwrap())
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn name(&self, idx: usize) -> &str {
+        &self.names[idx]
+    }
+
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n.eq_ignore_ascii_case(name))
+    }
+
+    pub fn beats(&self, winner: usize, loser: usize) -> bool {
+        self.beats[winner][loser]
+    }
+
+    pub fn outcome(&self, player: usize, opponent: usize) -> Outcome {
+        if self.beats(player, opponent) {
+            Outcome::Win
+        } else if self.beats(opponent, player) {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /*
+     * Every move that gets `outcome` when played against `opponent`
+     */
+    pub fn responses(&self, opponent: usize, outcome: Outcome) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(move |&player| self.outcome(player, opponent) == outcome)
+    }
+
+    pub fn winning_moves(&self, opponent: usize) -> impl Iterator<Item = usize> + '_ {
+        self.responses(opponent, Outcome::Win)
+    }
+
+    pub fn losing_moves(&self, opponent: usize) -> impl Iterator<Item = usize> + '_ {
+        self.responses(opponent, Outcome::Lose)
+    }
+
+    pub fn drawing_moves(&self, opponent: usize) -> impl Iterator<Item = usize> + '_ {
+        self.responses(opponent, Outcome::Draw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Outcome, Rules};
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        let rules = Rules::rock_paper_scissors();
+        let rock = rules.index_of("rock").unwrap();
+        let paper = rules.index_of("Paper").unwrap();
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, paper), Outcome::Lose);
+        assert_eq!(rules.outcome(rock, rock), Outcome::Draw);
+        assert_eq!(rules.winning_moves(rock).collect::<Vec<_>>(), vec![paper]);
+        assert_eq!(rules.drawing_moves(rock).collect::<Vec<_>>(), vec![rock]);
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let rules = Rules::cyclic(&["Rock", "Spock", "Paper", "Lizard", "Scissors"]).unwrap();
+        let move_of = |name| rules.index_of(name).unwrap();
+        assert!(rules.beats(move_of("Lizard"), move_of("Spock")));
+        assert!(rules.beats(move_of("Rock"), move_of("Lizard")));
+        assert!(rules.beats(move_of("Spock"), move_of("Scissors")));
+        for opponent in 0..rules.len() {
+            assert_eq!(rules.winning_moves(opponent).count(), 2);
+            assert_eq!(rules.losing_moves(opponent).count(), 2);
+        }
+        assert!(Rules::cyclic(&["Rock", "Paper"]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let names = vec![String::from("A"), String::from("B")];
+        assert!(Rules::new(names.clone(), &[(0, 1), (1, 0)]).is_err());
+        assert!(Rules::new(names.clone(), &[(0, 0)]).is_err());
+        assert!(Rules::new(names, &[(0, 2)]).is_err());
+    }
+}
@@ -0,0 +1,444 @@
+use super::config::Scoring;
+use super::rules::Outcome;
+use super::{PRSMove, PRSRound};
+use crate::error::AocError;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/*
+ * A player choosing its next move, `history` holds every earlier round seen
+ * from this player's side (`player` is its own move)
+ */
+pub trait Strategy {
+    fn name(&self) -> &str;
+    fn next_move(&mut self, history: &[PRSRound]) -> PRSMove;
+}
+
+pub struct Constant {
+    name: String,
+    shape: PRSMove,
+}
+
+impl Constant {
+    pub fn new(shape: PRSMove) -> Self {
+        Constant {
+            name: format!("always-{:?}", shape).to_lowercase(),
+            shape,
+        }
+    }
+}
+
+impl Strategy for Constant {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn next_move(&mut self, _history: &[PRSRound]) -> PRSMove {
+        self.shape
+    }
+}
+
+/*
+ * Uniformly random moves from a splitmix64 generator, same seed gives same game
+ */
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl Strategy for Random {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn next_move(&mut self, _history: &[PRSRound]) -> PRSMove {
+        PRSMove::from_index((self.next_u64() % 3) as usize)
+    }
+}
+
+/*
+ * Beats the opponent's most frequent move so far, Rock when nothing was played yet
+ * Counts are kept between calls, only rounds not seen before are added
+ */
+#[derive(Default)]
+pub struct Frequency {
+    counts: [u64; 3],
+    seen: usize,
+}
+
+impl Strategy for Frequency {
+    fn name(&self) -> &str {
+        "frequency"
+    }
+
+    fn next_move(&mut self, history: &[PRSRound]) -> PRSMove {
+        // a shorter history is a new match
+        if history.len() < self.seen {
+            *self = Frequency::default();
+        }
+        history[self.seen..]
+            .iter()
+            .for_each(|round| self.counts[round.opponent.index()] += 1);
+        self.seen = history.len();
+        if history.is_empty() {
+            return PRSMove::Rock;
+        }
+        let counts = &self.counts;
+        // max_by_key keeps the last maximum, so search backwards to prefer Rock on ties
+        let most_frequent = (0..3).rev().max_by_key(|&idx| counts[idx]).unwrap();
+        PRSMove::from_index(most_frequent).get_winning()
+    }
+}
+
+// plays whatever the opponent played last round, Rock first
+pub struct Mirror;
+
+impl Strategy for Mirror {
+    fn name(&self) -> &str {
+        "mirror"
+    }
+
+    fn next_move(&mut self, history: &[PRSRound]) -> PRSMove {
+        history.last().map_or(PRSMove::Rock, |round| round.opponent)
+    }
+}
+
+/*
+ * Replays a fixed list of moves, starting over when it runs out
+ */
+pub struct Scripted {
+    name: String,
+    moves: Vec<PRSMove>,
+}
+
+impl Scripted {
+    pub fn new(name: &str, moves: Vec<PRSMove>) -> Self {
+        Scripted {
+            name: name.to_string(),
+            moves,
+        }
+    }
+
+    // second column read as moves, like part 1
+    pub fn follow_guide(game: &[PRSRound]) -> Self {
+        Scripted::new("guide", game.iter().map(|round| round.player).collect())
+    }
+
+    // first column, the elves' own moves
+    pub fn guide_opponent(game: &[PRSRound]) -> Self {
+        Scripted::new("elves", game.iter().map(|round| round.opponent).collect())
+    }
+}
+
+impl Strategy for Scripted {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn next_move(&mut self, history: &[PRSRound]) -> PRSMove {
+        if self.moves.is_empty() {
+            return PRSMove::Rock;
+        }
+        self.moves[history.len() % self.moves.len()]
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Kind {
+    AlwaysRock,
+    Random,
+    Frequency,
+    Mirror,
+    Guide,
+    Elves,
+}
+
+impl Kind {
+    pub const ALL: [Kind; 6] = [
+        Kind::AlwaysRock,
+        Kind::Random,
+        Kind::Frequency,
+        Kind::Mirror,
+        Kind::Guide,
+        Kind::Elves,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Kind::AlwaysRock => "always-rock",
+            Kind::Random => "random",
+            Kind::Frequency => "frequency",
+            Kind::Mirror => "mirror",
+            Kind::Guide => "guide",
+            Kind::Elves => "elves",
+        }
+    }
+
+    // strategies keep state, so every match gets fresh ones
+    pub fn build(&self, game: &[PRSRound], seed: u64) -> Box<dyn Strategy> {
+        match self {
+            Kind::AlwaysRock => Box::new(Constant::new(PRSMove::Rock)),
+            Kind::Random => Box::new(Random::new(seed)),
+            Kind::Frequency => Box::new(Frequency::default()),
+            Kind::Mirror => Box::new(Mirror),
+            Kind::Guide => Box::new(Scripted::follow_guide(game)),
+            Kind::Elves => Box::new(Scripted::guide_opponent(game)),
+        }
+    }
+}
+
+impl FromStr for Kind {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Kind::ALL
+            .iter()
+            .find(|kind| kind.name() == s)
+            .copied()
+            .ok_or_else(|| {
+                let names: Vec<&str> = Kind::ALL.iter().map(|kind| kind.name()).collect();
+                AocError::semantic(format!(
+                    "unknown strategy `{}`, expected one of {}",
+                    s,
+                    names.join(", ")
+                ))
+            })
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Tally {
+    pub wins: u64,
+    pub draws: u64,
+    pub losses: u64,
+    pub score: u64,
+}
+
+impl Tally {
+    fn add(&mut self, round: &PRSRound, scoring: &Scoring) {
+        match round.outcome() {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Lose => self.losses += 1,
+        }
+        self.score += round.score_with(scoring);
+    }
+
+    pub fn rounds(&self) -> u64 {
+        self.wins + self.draws + self.losses
+    }
+
+    // percentage of rounds
+    pub fn rate(&self, count: u64) -> f64 {
+        if self.rounds() == 0 {
+            return 0.0;
+        }
+        count as f64 * 100.0 / self.rounds() as f64
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MatchResult {
+    pub first: String,
+    pub second: String,
+    pub first_tally: Tally,
+    pub second_tally: Tally,
+}
+
+pub fn play(
+    first: &mut dyn Strategy,
+    second: &mut dyn Strategy,
+    rounds: usize,
+    scoring: &Scoring,
+) -> MatchResult {
+    let mut first_history = Vec::new();
+    let mut second_history = Vec::new();
+    let mut first_tally = Tally::default();
+    let mut second_tally = Tally::default();
+
+    for _ in 0..rounds {
+        let first_move = first.next_move(&first_history);
+        let second_move = second.next_move(&second_history);
+        let first_round = PRSRound {
+            opponent: second_move,
+            player: first_move,
+        };
+        let second_round = PRSRound {
+            opponent: first_move,
+            player: second_move,
+        };
+        first_tally.add(&first_round, scoring);
+        second_tally.add(&second_round, scoring);
+        first_history.push(first_round);
+        second_history.push(second_round);
+    }
+
+    MatchResult {
+        first: first.name().to_string(),
+        second: second.name().to_string(),
+        first_tally,
+        second_tally,
+    }
+}
+
+// histories are kept whole, and the largest scores still fit in u64 at this length
+pub const MAX_ROUNDS: usize = 10_000_000;
+
+/*
+ * Every pair of distinct strategies plays one match
+ */
+pub fn round_robin(
+    kinds: &[Kind],
+    game: &[PRSRound],
+    rounds: usize,
+    seed: u64,
+    scoring: &Scoring,
+) -> Result<Vec<MatchResult>, AocError> {
+    if rounds > MAX_ROUNDS {
+        return Err(AocError::semantic(format!(
+            "at most {} rounds per match, got {}",
+            MAX_ROUNDS, rounds
+        )));
+    }
+    let mut results = Vec::new();
+    for (first_idx, first) in kinds.iter().enumerate() {
+        for second in &kinds[first_idx + 1..] {
+            // two random players must not mirror each other
+            let mut first = first.build(game, seed);
+            let mut second = second.build(game, seed.wrapping_add(1));
+            results.push(play(first.as_mut(), second.as_mut(), rounds, scoring));
+        }
+    }
+    Ok(results)
+}
+
+pub struct Table<'a>(pub &'a [MatchResult]);
+
+impl<'a> Display for Table<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<12} {:<12} {:>7} {:>7} {:>7} {:>8} {:>8}",
+            "first", "second", "win %", "draw %", "loss %", "score", "score"
+        )?;
+        for result in self.0 {
+            let tally = &result.first_tally;
+            writeln!(
+                f,
+                "{:<12} {:<12} {:>7.1} {:>7.1} {:>7.1} {:>8} {:>8}",
+                result.first,
+                result.second,
+                tally.rate(tally.wins),
+                tally.rate(tally.draws),
+                tally.rate(tally.losses),
+                tally.score,
+                result.second_tally.score
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        play, round_robin, Constant, Frequency, Kind, Mirror, Random, Strategy, MAX_ROUNDS,
+    };
+    use crate::day2::config::Scoring;
+    use crate::day2::{parse_game, score_strategy, PRSMove};
+
+    #[test]
+    fn test_play() {
+        let mut rock = Constant::new(PRSMove::Rock);
+        let mut paper = Constant::new(PRSMove::Paper);
+        let result = play(&mut rock, &mut paper, 10, &Scoring::default());
+        assert_eq!(result.first, "always-rock");
+        assert_eq!(
+            (result.first_tally.losses, result.first_tally.score),
+            (10, 10)
+        );
+        assert_eq!(
+            (result.second_tally.wins, result.second_tally.score),
+            (10, 80)
+        );
+
+        // mirror copies rock from round 2 on
+        let mut rock = Constant::new(PRSMove::Rock);
+        let result = play(&mut rock, &mut Mirror, 5, &Scoring::default());
+        assert_eq!(result.second_tally.draws, 5);
+
+        let scoring = Scoring {
+            shape: [100, 200, 300],
+            outcome: [0, 0, 0],
+        };
+        let mut rock = Constant::new(PRSMove::Rock);
+        let mut paper = Constant::new(PRSMove::Paper);
+        let result = play(&mut rock, &mut paper, 10, &scoring);
+        assert_eq!(
+            (result.first_tally.score, result.second_tally.score),
+            (1000, 2000)
+        );
+    }
+
+    #[test]
+    fn test_frequency() {
+        let round = |opponent| super::PRSRound {
+            opponent,
+            player: PRSMove::Rock,
+        };
+        let history = vec![
+            round(PRSMove::Paper),
+            round(PRSMove::Scissors),
+            round(PRSMove::Scissors),
+        ];
+        let mut frequency = Frequency::default();
+        assert_eq!(frequency.next_move(&[]), PRSMove::Rock);
+        // ties prefer the earlier move, Paper over Scissors
+        assert_eq!(frequency.next_move(&history[..2]), PRSMove::Scissors);
+        assert_eq!(frequency.next_move(&history), PRSMove::Rock);
+        // starting over forgets the previous match
+        assert_eq!(frequency.next_move(&history[..1]), PRSMove::Scissors);
+    }
+
+    #[test]
+    fn test_random_is_seeded() {
+        let moves = |seed| {
+            let mut random = Random::new(seed);
+            (0..20).map(|_| random.next_move(&[])).collect::<Vec<_>>()
+        };
+        assert_eq!(moves(7), moves(7));
+        assert_ne!(moves(7), moves(8));
+    }
+
+    #[test]
+    fn test_guide_against_elves() {
+        let (_, game) = parse_game(b"A Y\nB X\nC Z\n").unwrap();
+        let results = round_robin(
+            &[Kind::Guide, Kind::Elves, Kind::Frequency],
+            &game,
+            3,
+            0,
+            &Scoring::default(),
+        )
+        .unwrap();
+        assert_eq!(results.len(), 3);
+        // replaying the guide against the elves is part 1
+        assert_eq!(results[0].first_tally.score, score_strategy(&game).unwrap());
+        assert_eq!("mirror".parse::<Kind>().unwrap(), Kind::Mirror);
+        assert!("paper".parse::<Kind>().is_err());
+        assert!(round_robin(&Kind::ALL, &game, MAX_ROUNDS + 1, 0, &Scoring::default()).is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use super::{group_badges, ItemSet, Rucksack};
+use crate::error::AocError;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Grouping {
+    // consecutive groups sharing no rucksack, like part 2
+    Disjoint,
+    // every run of `size` consecutive rucksacks
+    Sliding,
+}
+
+impl FromStr for Grouping {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "disjoint" => Ok(Grouping::Disjoint),
+            "sliding" => Ok(Grouping::Sliding),
+            _ => Err(AocError::semantic(format!(
+                "unknown grouping `{}`, expected disjoint or sliding",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GroupReport {
+    // 1-based, lines are first_line..first_line + rucksacks
+    pub group: usize,
+    pub first_line: usize,
+    // less than the group size for a trailing incomplete group
+    pub rucksacks: usize,
+    pub common: ItemSet,
+}
+
+impl Display for GroupReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "group {} (lines {}-{}): {} common",
+            self.group,
+            self.first_line,
+            self.first_line + self.rucksacks - 1,
+            self.common.len()
+        )?;
+        for (item, priority) in self.common.items().zip(self.common.priorities()) {
+            write!(f, " {}={}", item, priority)?;
+        }
+        Ok(())
+    }
+}
+
+/*
+ * Split rucksacks of any kind into groups, each with the index of its first rucksack
+ */
+pub fn split<T>(
+    rucksacks: &[T],
+    size: usize,
+    grouping: Grouping,
+) -> Result<Vec<(usize, &[T])>, AocError> {
+    if size == 0 {
+        return Err(AocError::semantic("group size must be at least 1"));
+    }
+    Ok(match grouping {
+        Grouping::Disjoint => rucksacks
+            .chunks(size)
+            .enumerate()
+            .map(|(idx, group)| (idx * size, group))
+            .collect(),
+        Grouping::Sliding => rucksacks.windows(size).enumerate().collect(),
+    })
+}
+
+/*
+ * Items common to every rucksack of each group, however many there are
+ */
+pub fn analyse_groups(
+    rucksacks: &[Rucksack],
+    size: usize,
+    grouping: Grouping,
+) -> Result<Vec<GroupReport>, AocError> {
+    Ok(split(rucksacks, size, grouping)?
+        .into_iter()
+        .enumerate()
+        .map(|(group_idx, (start, group))| GroupReport {
+            group: group_idx + 1,
+            first_line: start + 1,
+            rucksacks: group.len(),
+            common: group_badges(group),
+        })
+        .collect())
+}
+
+// sum of priorities of every common item of every group
+pub fn sum_of_common_items(reports: &[GroupReport]) -> u32 {
+    reports
+        .iter()
+        .map(|report| report.common.priority_sum())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{analyse_groups, sum_of_common_items, Grouping};
+    use crate::day3::parse_rucksacks;
+
+    #[test]
+    fn test_groups() {
+        let rucksacks = parse_rucksacks("abcd\nabce\nabxy\nbbzz\n".as_bytes()).unwrap();
+
+        let disjoint = analyse_groups(&rucksacks, 3, Grouping::Disjoint).unwrap();
+        assert_eq!(disjoint.len(), 2);
+        assert_eq!(disjoint[0].common.to_string(), "ab");
+        assert_eq!(
+            disjoint[0].to_string(),
+            "group 1 (lines 1-3): 2 common a=1 b=2"
+        );
+        assert_eq!(
+            disjoint[1].to_string(),
+            "group 2 (lines 4-4): 2 common b=2 z=26"
+        );
+
+        let sliding = analyse_groups(&rucksacks, 3, Grouping::Sliding).unwrap();
+        assert_eq!(sliding.len(), 2);
+        assert_eq!(sliding[1].first_line, 2);
+        assert_eq!(sliding[1].common.to_string(), "b");
+        assert_eq!(sum_of_common_items(&sliding), 1 + 2 + 2);
+
+        let pairs = analyse_groups(&rucksacks, 2, Grouping::Disjoint).unwrap();
+        assert_eq!(pairs[1].common.to_string(), "b");
+        assert!(
+            analyse_groups(&rucksacks[2..], 1, Grouping::Disjoint).unwrap()[0]
+                .common
+                .contains('x')
+        );
+        assert!(analyse_groups(&rucksacks, 0, Grouping::Sliding).is_err());
+        assert_eq!("sliding".parse::<Grouping>().unwrap(), Grouping::Sliding);
+    }
+}
@@ -0,0 +1,380 @@
+use crate::error::AocError;
+use crate::solution::Solution;
+use groups::{split, Grouping};
+use priority::{Letters, Priority};
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::ops::{BitAnd, BitOr};
+use validate::{check_group, check_line};
+
+pub mod groups;
+pub mod priority;
+pub mod validate;
+
+/*
+ * Items of any catalogue, `ItemSet` for the puzzle's letters and `BTreeSet<char>` for the rest
+ * Items are checked against the catalogue's `Priority` before they are added
+ */
+pub trait ItemStore: Default + Clone {
+    fn add(&mut self, item: char);
+    fn common(&self, other: &Self) -> Self;
+    fn merged(&self, other: &Self) -> Self;
+    fn size(&self) -> usize;
+    fn members(&self) -> Vec<char>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rucksack<S = ItemSet> {
+    pub compartment_a: S,
+    pub compartment_b: S,
+}
+
+impl<S: ItemStore> Rucksack<S> {
+    // `line` was checked by `validate::check_line`
+    fn from_line(line: &str) -> Self {
+        let items: Vec<char> = line.chars().collect();
+        let (a, b) = items.split_at(items.len() / 2);
+        let store = |items: &[char]| {
+            let mut store = S::default();
+            items.iter().for_each(|&item| store.add(item));
+            store
+        };
+        Rucksack {
+            compartment_a: store(a),
+            compartment_b: store(b),
+        }
+    }
+
+    pub fn items(&self) -> S {
+        self.compartment_a.merged(&self.compartment_b)
+    }
+
+    pub fn shared(&self) -> S {
+        self.compartment_a.common(&self.compartment_b)
+    }
+}
+
+// checked before the cast, which keeps only the low byte of `x`
+pub fn score(x: char) -> Result<u8, AocError> {
+    if x.is_ascii_lowercase() {
+        return Ok(x as u8 - 96);
+    } else if x.is_ascii_uppercase() {
+        return Ok(x as u8 - 38);
+    }
+    Err(AocError::semantic(format!("Invalid character {:?}", x)))
+}
+
+/*
+ * Set of items stored as a bitmask, bit `n` is the item with priority `n`
+ */
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub fn from_items(items: &str) -> Result<Self, AocError> {
+        let mut set = ItemSet::default();
+        for item in items.chars() {
+            set.insert(item)?;
+        }
+        Ok(set)
+    }
+
+    pub fn insert(&mut self, item: char) -> Result<(), AocError> {
+        self.0 |= 1 << score(item)?;
+        Ok(())
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        score(item).is_ok_and(|priority| self.0 & (1 << priority) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    // priorities of the items, lowest first
+    pub fn priorities(&self) -> impl Iterator<Item = u8> {
+        let mut rest = self.0;
+        std::iter::from_fn(move || {
+            if rest == 0 {
+                return None;
+            }
+            let priority = rest.trailing_zeros() as u8;
+            rest &= rest - 1;
+            Some(priority)
+        })
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = char> {
+        self.priorities().map(item_of)
+    }
+
+    pub fn priority_sum(&self) -> u32 {
+        self.priorities().map(|priority| priority as u32).sum()
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        self.union(&other)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        self.intersection(&other)
+    }
+}
+
+impl ItemStore for ItemSet {
+    // anything but a letter was rejected by `Letters`
+    fn add(&mut self, item: char) {
+        let _ = self.insert(item);
+    }
+
+    fn common(&self, other: &Self) -> Self {
+        self.intersection(other)
+    }
+
+    fn merged(&self, other: &Self) -> Self {
+        self.union(other)
+    }
+
+    fn size(&self) -> usize {
+        self.len()
+    }
+
+    fn members(&self) -> Vec<char> {
+        self.items().collect()
+    }
+}
+
+impl ItemStore for BTreeSet<char> {
+    fn add(&mut self, item: char) {
+        self.insert(item);
+    }
+
+    fn common(&self, other: &Self) -> Self {
+        self.intersection(other).copied().collect()
+    }
+
+    fn merged(&self, other: &Self) -> Self {
+        self.union(other).copied().collect()
+    }
+
+    fn size(&self) -> usize {
+        self.len()
+    }
+
+    fn members(&self) -> Vec<char> {
+        self.iter().copied().collect()
+    }
+}
+
+impl Display for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.items().try_for_each(|item| write!(f, "{}", item))
+    }
+}
+
+// inverse of `score`
+fn item_of(priority: u8) -> char {
+    match priority {
+        1..=26 => (b'a' + priority - 1) as char,
+        _ => (b'A' + priority - 27) as char,
+    }
+}
+
+#[test]
+fn test_score() {
+    assert_eq!(score('a').unwrap(), 1);
+    assert_eq!(score('A').unwrap(), 27);
+    assert_eq!(score('z').unwrap(), 26);
+    assert_eq!(score('Z').unwrap(), 52);
+    assert!(matches!(score('0'), Err(AocError::Semantic(_))));
+}
+
+/*
+ * Rucksacks of any catalogue, stopping at the first bad line
+ */
+pub fn parse_rucksacks_with<R: BufRead, P: Priority + ?Sized>(
+    reader: R,
+    priority: &P,
+) -> Result<Vec<Rucksack<P::Items>>, AocError> {
+    let mut rucksacks = Vec::new();
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        if let Some(problem) = check_line(line_idx + 1, &line, priority).first() {
+            return Err(problem.to_error(&line));
+        }
+        rucksacks.push(Rucksack::from_line(&line));
+    }
+    Ok(rucksacks)
+}
+
+pub fn parse_rucksacks<R: BufRead>(reader: R) -> Result<Vec<Rucksack>, AocError> {
+    parse_rucksacks_with(reader, &Letters)
+}
+
+// priorities of `items`, u64 with checked additions since weights can be large
+fn priority_total<P: Priority + ?Sized>(
+    items: impl IntoIterator<Item = char>,
+    priority: &P,
+) -> Result<u64, AocError> {
+    checked_total(
+        items
+            .into_iter()
+            .filter_map(|item| priority.priority(item))
+            .map(u64::from),
+    )
+}
+
+fn checked_total(values: impl IntoIterator<Item = u64>) -> Result<u64, AocError> {
+    values
+        .into_iter()
+        .try_fold(0u64, |total, value| total.checked_add(value))
+        .ok_or_else(|| AocError::semantic("priority total overflows u64"))
+}
+
+/*
+ * Puzzle 1 for any catalogue: items present in both compartments
+ */
+pub fn sum_of_shared_items_with<P: Priority + ?Sized>(
+    rucksacks: &[Rucksack<P::Items>],
+    priority: &P,
+) -> Result<u64, AocError> {
+    let totals = rucksacks
+        .iter()
+        .map(|r| priority_total(r.shared().members(), priority))
+        .collect::<Result<Vec<u64>, AocError>>()?;
+    checked_total(totals)
+}
+
+/*
+ * Puzzle 1 with the puzzle's letters, at most 52 per rucksack so nothing can overflow
+ */
+pub fn sum_of_shared_items(rucksacks: &[Rucksack]) -> u64 {
+    rucksacks
+        .iter()
+        .map(|r| r.shared().priority_sum() as u64)
+        .sum()
+}
+
+// items carried by every rucksack of the group
+pub fn group_badges<S: ItemStore>(group: &[Rucksack<S>]) -> S {
+    group
+        .iter()
+        .map(Rucksack::items)
+        .reduce(|acc, x| acc.common(&x))
+        .unwrap_or_default()
+}
+
+/*
+ * Puzzle 2 for any catalogue: every group must be complete and share a single badge
+ * Part 2 itself is groups of 3 with `Grouping::Disjoint`
+ */
+pub fn sum_of_badges_with<P: Priority + ?Sized>(
+    rucksacks: &[Rucksack<P::Items>],
+    size: usize,
+    grouping: Grouping,
+    priority: &P,
+) -> Result<u64, AocError> {
+    let totals = split(rucksacks, size, grouping)?
+        .into_iter()
+        .enumerate()
+        .map(|(group_idx, (first, group))| {
+            let badge = check_group(group_idx + 1, first, group, size)
+                .map_err(|problem| AocError::semantic(problem.to_string()))?;
+            priority_total(badge.members(), priority)
+        })
+        .collect::<Result<Vec<u64>, AocError>>()?;
+    checked_total(totals)
+}
+
+/*
+ * Puzzle 2: the single item common to every rucksack in a group of three
+ */
+pub fn sum_of_badges(rucksacks: &[Rucksack]) -> Result<u64, AocError> {
+    sum_of_badges_with(rucksacks, 3, Grouping::Disjoint, &Letters)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &[u8]) -> Result<Self::Input, AocError> {
+        parse_rucksacks(input)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<String, AocError> {
+        Ok(sum_of_shared_items(rucksacks).to_string())
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<String, AocError> {
+        Ok(sum_of_badges(rucksacks)?.to_string())
+    }
+}
+
+#[test]
+fn test_item_set() {
+    let a = ItemSet::from_items("vJrwpWtwJgWr").unwrap();
+    let b = ItemSet::from_items("hcsFMMfFFhFp").unwrap();
+    let shared = a & b;
+    assert_eq!(shared.to_string(), "p");
+    assert_eq!(shared.priority_sum(), 16);
+    assert!(a.contains('J') && !a.contains('h') && !a.contains('1'));
+    assert_eq!((a | b).len(), a.len() + b.len() - 1);
+    assert_eq!(
+        ItemSet::from_items("zaZA")
+            .unwrap()
+            .items()
+            .collect::<String>(),
+        "azAZ"
+    );
+    assert_eq!(ItemSet::from_items("aZ").unwrap().priority_sum(), 53);
+    assert!(ItemSet::from_items("a1").is_err());
+
+    // U+0161 and U+0141 have the low bytes of 'a' and 'A'
+    assert!(ItemSet::from_items("š").is_err());
+    let mut set = ItemSet::from_items("aA").unwrap();
+    assert!(!set.contains('š') && !set.contains('Ł'));
+    assert!(set.insert('Ł').is_err());
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_parse_rucksacks() {
+    let input = include_bytes!("../../examples/day3/sample.txt");
+    let rucksacks = parse_rucksacks(&input[..]).unwrap();
+    assert_eq!(sum_of_shared_items(&rucksacks), 157);
+    assert_eq!(sum_of_badges(&rucksacks).unwrap(), 70);
+    match parse_rucksacks("abcd\nab1\n".as_bytes()) {
+        Err(AocError::Parse {
+            line: 2,
+            column: 3,
+            message,
+            ..
+        }) => assert_eq!(message, "'1' is not an item of the catalogue"),
+        other => panic!("expected parse error, got {:?}", other),
+    }
+    assert!(checked_total(vec![u64::MAX, 1]).is_err());
+}
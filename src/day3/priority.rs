@@ -0,0 +1,197 @@
+use super::{ItemSet, ItemStore};
+use crate::error::AocError;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+/*
+ * How much an item is worth, None for characters outside the catalogue
+ * `Items` stores a rucksack's items, only the puzzle's letters fit the `ItemSet` bitmask
+ */
+pub trait Priority {
+    type Items: ItemStore;
+
+    fn priority(&self, item: char) -> Option<u32>;
+}
+
+// the puzzle's scheme: a-z are 1-26, A-Z are 27-52
+pub struct Letters;
+
+impl Priority for Letters {
+    type Items = ItemSet;
+
+    fn priority(&self, item: char) -> Option<u32> {
+        super::score(item).ok().map(u32::from)
+    }
+}
+
+// items listed in order, the first one is worth 1
+pub struct Alphabet {
+    items: Vec<char>,
+}
+
+impl Alphabet {
+    pub fn new(items: &str) -> Result<Self, AocError> {
+        let items: Vec<char> = items.chars().collect();
+        if items.is_empty() {
+            return Err(AocError::semantic("alphabet is empty"));
+        }
+        if let Some(idx) = (1..items.len()).find(|&idx| items[..idx].contains(&items[idx])) {
+            return Err(AocError::semantic(format!(
+                "{:?} is listed twice in the alphabet",
+                items[idx]
+            )));
+        }
+        Ok(Alphabet { items })
+    }
+}
+
+impl Priority for Alphabet {
+    type Items = BTreeSet<char>;
+
+    fn priority(&self, item: char) -> Option<u32> {
+        self.items
+            .iter()
+            .position(|&c| c == item)
+            .map(|idx| idx as u32 + 1)
+    }
+}
+
+// any Unicode letter, worth its code point
+pub struct UnicodeLetters;
+
+impl Priority for UnicodeLetters {
+    type Items = BTreeSet<char>;
+
+    fn priority(&self, item: char) -> Option<u32> {
+        if item.is_alphabetic() {
+            Some(item as u32)
+        } else {
+            None
+        }
+    }
+}
+
+/*
+ * Weights from a TOML file, one item per key:
+ *
+ *   a = 1
+ *   "é" = 30
+ */
+pub struct Weights {
+    weights: HashMap<char, u32>,
+}
+
+impl Weights {
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        let table: HashMap<String, u32> =
+            toml::from_str(input).map_err(|err| AocError::from_toml(input, err))?;
+        let mut weights = HashMap::with_capacity(table.len());
+        for (key, weight) in table {
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                (Some(item), None) => {
+                    weights.insert(item, weight);
+                }
+                _ => {
+                    return Err(AocError::semantic(format!(
+                        "weight key {:?} must be a single item",
+                        key
+                    )))
+                }
+            }
+        }
+        Ok(Weights { weights })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+}
+
+impl Priority for Weights {
+    type Items = BTreeSet<char>;
+
+    fn priority(&self, item: char) -> Option<u32> {
+        self.weights.get(&item).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alphabet, Letters, Priority, UnicodeLetters, Weights};
+    use crate::day3::groups::Grouping;
+    use crate::day3::{parse_rucksacks_with, sum_of_badges_with, sum_of_shared_items_with};
+    use crate::error::AocError;
+
+    #[test]
+    fn test_schemes() {
+        assert_eq!(Letters.priority('A'), Some(27));
+        assert_eq!(Letters.priority('é'), None);
+        // U+0161, its low byte is 'a'
+        assert_eq!(Letters.priority('š'), None);
+        let alphabet = Alphabet::new("xyzé").unwrap();
+        assert_eq!(alphabet.priority('é'), Some(4));
+        assert_eq!(alphabet.priority('a'), None);
+        assert!(Alphabet::new("xyx").is_err());
+        assert_eq!(UnicodeLetters.priority('ж'), Some(0x436));
+        assert_eq!(UnicodeLetters.priority('1'), None);
+
+        let weights = Weights::parse("a = 10\n\"é\" = 3\n").unwrap();
+        assert_eq!(weights.priority('é'), Some(3));
+        assert!(matches!(
+            Weights::parse("ab = 1\n"),
+            Err(AocError::Semantic(_))
+        ));
+        assert!(matches!(
+            Weights::parse("a = \"x\"\n"),
+            Err(AocError::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_letters_match_puzzle() {
+        let input = include_str!("../../examples/day3/sample.txt");
+        let rucksacks = parse_rucksacks_with(input.as_bytes(), &Letters).unwrap();
+        assert_eq!(sum_of_shared_items_with(&rucksacks, &Letters).unwrap(), 157);
+        assert_eq!(
+            sum_of_badges_with(&rucksacks, 3, Grouping::Disjoint, &Letters).unwrap(),
+            70
+        );
+    }
+
+    #[test]
+    fn test_other_catalogue() {
+        let alphabet = Alphabet::new("абвгд").unwrap();
+        let rucksacks = parse_rucksacks_with("абвб\nагвд\nвввв\n".as_bytes(), &alphabet).unwrap();
+        assert_eq!(sum_of_shared_items_with(&rucksacks, &alphabet).unwrap(), 5);
+        assert_eq!(
+            sum_of_badges_with(&rucksacks, 3, Grouping::Disjoint, &alphabet).unwrap(),
+            3
+        );
+        // the first two rucksacks share а and в, the last two only в
+        assert!(sum_of_badges_with(&rucksacks, 2, Grouping::Disjoint, &alphabet).is_err());
+        assert_eq!(
+            sum_of_badges_with(&rucksacks[1..], 2, Grouping::Sliding, &alphabet).unwrap(),
+            3
+        );
+        assert!(matches!(
+            parse_rucksacks_with("šš\n".as_bytes(), &Letters),
+            Err(AocError::Parse { column: 1, .. })
+        ));
+        assert!(matches!(
+            parse_rucksacks_with("абx\n".as_bytes(), &alphabet),
+            Err(AocError::Parse { column: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_large_weights() {
+        let weights = Weights::parse("a = 4294967295\nb = 4294967295\n").unwrap();
+        let rucksacks = parse_rucksacks_with("aa\nbb\nab\n".as_bytes(), &weights).unwrap();
+        // two shared items worth u32::MAX each, more than a u32 holds
+        assert_eq!(
+            sum_of_shared_items_with(&rucksacks, &weights).unwrap(),
+            2 * u32::MAX as u64
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use super::priority::{Letters, Priority};
+use super::{group_badges, sum_of_shared_items, ItemStore, Rucksack};
+use crate::error::AocError;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Problem {
+    NotAnItem {
+        line: usize,
+        column: usize,
+        item: char,
+    },
+    OddLength {
+        line: usize,
+        length: usize,
+    },
+    // groups are 1-based, `first_line` is the line of their first rucksack
+    IncompleteGroup {
+        group: usize,
+        first_line: usize,
+        rucksacks: usize,
+        size: usize,
+    },
+    NoBadge {
+        group: usize,
+        first_line: usize,
+        size: usize,
+    },
+    SeveralBadges {
+        group: usize,
+        first_line: usize,
+        size: usize,
+        badges: String,
+    },
+}
+
+fn group_lines(group: usize, first_line: usize, rucksacks: usize) -> String {
+    format!(
+        "group {} (lines {}-{})",
+        group,
+        first_line,
+        first_line + rucksacks - 1
+    )
+}
+
+impl Problem {
+    // what is wrong, without where
+    pub fn message(&self) -> String {
+        match self {
+            Problem::NotAnItem { item, .. } => {
+                format!("{:?} is not an item of the catalogue", item)
+            }
+            Problem::OddLength { length, .. } => format!(
+                "{} items cannot be split into two equal compartments",
+                length
+            ),
+            Problem::IncompleteGroup {
+                rucksacks, size, ..
+            } => format!("only {} of {} rucksacks", rucksacks, size),
+            Problem::NoBadge { .. } => String::from("no item common to all rucksacks"),
+            Problem::SeveralBadges { badges, .. } => format!(
+                "{} common items `{}`, expected a single badge",
+                badges.chars().count(),
+                badges
+            ),
+        }
+    }
+
+    // line problems point into `snippet`, their line
+    pub fn to_error(&self, snippet: &str) -> AocError {
+        match *self {
+            Problem::NotAnItem { line, column, .. } => {
+                AocError::parse(line, column, snippet, self.message())
+            }
+            Problem::OddLength { line, length } => {
+                AocError::parse(line, length, snippet, self.message())
+            }
+            _ => AocError::semantic(self.to_string()),
+        }
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Problem::NotAnItem { line, column, .. } => {
+                write!(f, "line {}, column {}: ", line, column)?
+            }
+            Problem::OddLength { line, .. } => write!(f, "line {}: ", line)?,
+            Problem::IncompleteGroup {
+                group,
+                first_line,
+                rucksacks,
+                ..
+            } => write!(f, "{}: ", group_lines(group, first_line, rucksacks))?,
+            Problem::NoBadge {
+                group,
+                first_line,
+                size,
+            }
+            | Problem::SeveralBadges {
+                group,
+                first_line,
+                size,
+                ..
+            } => write!(f, "{}: ", group_lines(group, first_line, size))?,
+        }
+        write!(f, "{}", self.message())
+    }
+}
+
+/*
+ * Every problem of one line, `line` is 1-based
+ */
+pub fn check_line<P: Priority + ?Sized>(line: usize, items: &str, priority: &P) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = items
+        .chars()
+        .enumerate()
+        .filter(|&(_, item)| priority.priority(item).is_none())
+        .map(|(column, item)| Problem::NotAnItem {
+            line,
+            column: column + 1,
+            item,
+        })
+        .collect();
+    let length = items.chars().count();
+    if !length.is_multiple_of(2) {
+        problems.push(Problem::OddLength { line, length });
+    }
+    problems
+}
+
+/*
+ * The single badge of a group of `size` rucksacks, `first` is the 0-based index
+ * of its first rucksack
+ */
+pub fn check_group<S: ItemStore>(
+    group: usize,
+    first: usize,
+    rucksacks: &[Rucksack<S>],
+    size: usize,
+) -> Result<S, Problem> {
+    let first_line = first + 1;
+    if rucksacks.len() != size {
+        return Err(Problem::IncompleteGroup {
+            group,
+            first_line,
+            rucksacks: rucksacks.len(),
+            size,
+        });
+    }
+    let badges = group_badges(rucksacks);
+    match badges.size() {
+        1 => Ok(badges),
+        0 => Err(Problem::NoBadge {
+            group,
+            first_line,
+            size,
+        }),
+        _ => Err(Problem::SeveralBadges {
+            group,
+            first_line,
+            size,
+            badges: badges.members().into_iter().collect(),
+        }),
+    }
+}
+
+/*
+ * Every rucksack, None for lines with a problem, and every problem found
+ */
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Validation {
+    pub rucksacks: Vec<Option<Rucksack>>,
+    pub problems: Vec<Problem>,
+}
+
+pub fn validate<R: BufRead>(reader: R) -> Result<Validation, AocError> {
+    let mut rucksacks = Vec::new();
+    let mut problems = Vec::new();
+
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line_problems = check_line(line_idx + 1, &line, &Letters);
+        rucksacks.push(if line_problems.is_empty() {
+            Some(Rucksack::from_line(&line))
+        } else {
+            None
+        });
+        problems.extend(line_problems);
+    }
+
+    for (group_idx, group) in rucksacks.chunks(3).enumerate() {
+        // a bad line's rucksack is unknown, so is its group's badge
+        let Some(group) = group.iter().cloned().collect::<Option<Vec<Rucksack>>>() else {
+            if group.len() != 3 {
+                problems.push(Problem::IncompleteGroup {
+                    group: group_idx + 1,
+                    first_line: group_idx * 3 + 1,
+                    rucksacks: group.len(),
+                    size: 3,
+                });
+            }
+            continue;
+        };
+        if let Err(problem) = check_group(group_idx + 1, group_idx * 3, &group, 3) {
+            problems.push(problem);
+        }
+    }
+
+    Ok(Validation {
+        rucksacks,
+        problems,
+    })
+}
+
+impl Validation {
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    fn valid_rucksacks(&self) -> Vec<Rucksack> {
+        self.rucksacks.iter().flatten().cloned().collect()
+    }
+
+    // puzzle 1 over rucksacks without problems
+    pub fn sum_of_shared_items(&self) -> u64 {
+        sum_of_shared_items(&self.valid_rucksacks())
+    }
+
+    // puzzle 2 over complete groups with valid rucksacks and a single badge
+    pub fn sum_of_badges(&self) -> u64 {
+        self.rucksacks
+            .chunks(3)
+            .enumerate()
+            .filter_map(|(group_idx, group)| {
+                let group = group.iter().cloned().collect::<Option<Vec<Rucksack>>>()?;
+                check_group(group_idx + 1, group_idx * 3, &group, 3).ok()
+            })
+            .map(|badge| badge.priority_sum() as u64)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate, Problem};
+
+    #[test]
+    fn test_validate() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw1
+ab";
+        let validation = validate(input.as_bytes()).unwrap();
+        let problems: Vec<String> = validation.problems.iter().map(|p| p.to_string()).collect();
+        assert_eq!(
+            problems,
+            vec![
+                "line 6, column 25: '1' is not an item of the catalogue",
+                "line 6: 25 items cannot be split into two equal compartments",
+                "group 3 (lines 7-7): only 1 of 3 rucksacks",
+            ]
+        );
+        assert!(!validation.is_valid());
+        // line 6 is skipped, the rest is as in the example
+        assert_eq!(validation.sum_of_shared_items(), 16 + 38 + 42 + 22 + 20);
+        assert_eq!(validation.sum_of_badges(), 18);
+
+        let several = validate("ab\nab\nab\n".as_bytes()).unwrap();
+        assert!(matches!(
+            several.problems[..],
+            [Problem::SeveralBadges { group: 1, .. }]
+        ));
+        assert_eq!(
+            several.problems[0].to_string(),
+            "group 1 (lines 1-3): 2 common items `ab`, expected a single badge"
+        );
+
+        let short = validate("ab\nab\nab\nab\nab\n".as_bytes()).unwrap();
+        assert_eq!(
+            short.problems.last().unwrap().to_string(),
+            "group 2 (lines 4-5): only 2 of 3 rucksacks"
+        );
+
+        // the bad line's rucksack is unknown, so its group gets no badge problem
+        let unknown = validate("ab\ncd\nef1\n".as_bytes()).unwrap();
+        assert_eq!(unknown.problems.len(), 2);
+        assert_eq!(unknown.rucksacks[2], None);
+        assert!(!unknown
+            .problems
+            .iter()
+            .any(|problem| matches!(problem, Problem::NoBadge { .. })));
+    }
+}
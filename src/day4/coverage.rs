@@ -0,0 +1,181 @@
+use super::IntervalPair;
+use gcollections::ops::{Bounded, Cardinality, Difference, Empty, IsEmpty, Union};
+use interval::ops::Range;
+use interval::{Interval, IntervalSet};
+use std::fmt::{self, Display};
+
+/*
+ * Every assignment of every pair merged together
+ */
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Coverage {
+    // sections assigned to at least one elf, as disjoint intervals
+    pub covered: IntervalSet<u16>,
+    // unassigned sections between the first and last covered ones
+    pub gaps: IntervalSet<u16>,
+    pub assignments: usize,
+    // counts[i] is the number of assignments holding section first_section + i
+    first_section: u16,
+    counts: Vec<usize>,
+}
+
+impl Coverage {
+    pub fn sections(&self) -> usize {
+        self.covered.size() as usize
+    }
+
+    pub fn count(&self, section: u16) -> usize {
+        section
+            .checked_sub(self.first_section)
+            .and_then(|idx| self.counts.get(idx as usize))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    // (section, assignments) for every covered section, in order
+    pub fn counts(&self) -> impl Iterator<Item = (u16, usize)> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(move |(idx, &count)| (self.first_section + idx as u16, count))
+    }
+
+    // lowest section on ties, None when nothing is covered
+    pub fn most_covered(&self) -> Option<(u16, usize)> {
+        // max_by_key keeps the last maximum, so break ties on the section
+        self.counts()
+            .max_by_key(|&(section, count)| (count, std::cmp::Reverse(section)))
+    }
+}
+
+/*
+ * Empty assignments, which the parser never produces, cover nothing
+ */
+pub fn coverage(input_data: &[IntervalPair]) -> Coverage {
+    let assignments: Vec<&Interval<u16>> = input_data
+        .iter()
+        .flat_map(|(elf_first, elf_second)| vec![elf_first, elf_second])
+        .filter(|assignment| !assignment.is_empty())
+        .collect();
+    let covered = assignments.iter().fold(
+        IntervalSet::empty(),
+        |covered: IntervalSet<u16>, assignment| {
+            covered.union(&IntervalSet::new(assignment.lower(), assignment.upper()))
+        },
+    );
+    if covered.is_empty() {
+        return Coverage {
+            covered,
+            gaps: IntervalSet::empty(),
+            assignments: 0,
+            first_section: 0,
+            counts: Vec::new(),
+        };
+    }
+
+    let (first_section, last_section) = (covered.lower(), covered.upper());
+    let gaps = IntervalSet::new(first_section, last_section).difference(&covered);
+
+    // +1 where an assignment starts and -1 after it ends, summed left to right
+    let mut deltas = vec![0isize; (last_section - first_section) as usize + 2];
+    for assignment in &assignments {
+        deltas[(assignment.lower() - first_section) as usize] += 1;
+        deltas[(assignment.upper() - first_section) as usize + 1] -= 1;
+    }
+    let mut running = 0;
+    let counts = deltas[..deltas.len() - 1]
+        .iter()
+        .map(|delta| {
+            running += delta;
+            running as usize
+        })
+        .collect();
+
+    Coverage {
+        covered,
+        gaps,
+        assignments: assignments.len(),
+        first_section,
+        counts,
+    }
+}
+
+impl Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "assignments:  {}", self.assignments)?;
+        writeln!(
+            f,
+            "covered:      {} sections {}",
+            self.sections(),
+            self.covered
+        )?;
+        if self.gaps.is_empty() {
+            writeln!(f, "gaps:         none")?;
+        } else {
+            writeln!(
+                f,
+                "gaps:         {} sections {}",
+                self.gaps.size(),
+                self.gaps
+            )?;
+        }
+        if let Some((section, count)) = self.most_covered() {
+            writeln!(
+                f,
+                "most covered: section {} ({} assignments)",
+                section, count
+            )?;
+        }
+
+        writeln!(f, "\nsection  assignments")?;
+        for (section, count) in self.counts() {
+            writeln!(f, "{:>7}  {:>11}", section, count)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::coverage;
+    use crate::day4::parse_input;
+    use gcollections::ops::{Cardinality, IsEmpty};
+    use interval::ops::Range;
+    use interval::Interval;
+
+    #[test]
+    fn test_coverage_sample() {
+        let input = include_bytes!("../../examples/day4/sample.txt");
+        let (_, pairs) = parse_input(input).unwrap();
+        let coverage = coverage(&pairs);
+        assert_eq!(coverage.assignments, 12);
+        assert_eq!(coverage.covered.interval_count(), 1);
+        assert_eq!(coverage.sections(), 8);
+        assert!(coverage.gaps.is_empty());
+        assert_eq!(coverage.count(6), 8);
+        assert_eq!(coverage.count(1), 0);
+        assert_eq!(coverage.most_covered(), Some((6, 8)));
+        assert_eq!(coverage.counts().count(), 8);
+    }
+
+    #[test]
+    fn test_coverage_gaps() {
+        let pairs = vec![
+            (Interval::new(1, 2), Interval::new(10, 12)),
+            (Interval::new(3, 4), Interval::new(2, 3)),
+        ];
+        let coverage = coverage(&pairs);
+        assert_eq!(coverage.covered.interval_count(), 2);
+        assert_eq!(coverage.gaps.size(), 5);
+        assert_eq!(coverage.count(7), 0);
+        assert_eq!(coverage.most_covered(), Some((2, 2)));
+        assert!(coverage
+            .to_string()
+            .contains("gaps:         5 sections [5..9]"));
+
+        let nothing = super::coverage(&[]);
+        assert_eq!(nothing.most_covered(), None);
+        assert_eq!(nothing.sections(), 0);
+    }
+}
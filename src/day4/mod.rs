@@ -0,0 +1,105 @@
+use crate::error::{self, AocError};
+use crate::solution::Solution;
+use gcollections::ops::set::Overlap;
+use interval::ops::*;
+use interval::Interval;
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::combinator::{cut, eof, opt, verify};
+use nom::multi::many0;
+use nom::sequence::{terminated, tuple};
+use nom::IResult;
+
+pub mod coverage;
+pub mod relation;
+
+pub type IntervalPair = (Interval<u16>, Interval<u16>);
+
+/*
+ * `start-end`, not reversed, Interval<u16> cannot hold u16::MAX as a bound
+ */
+pub fn parse_sections(i: &[u8]) -> IResult<&[u8], Interval<u16>> {
+    let (input, (start, _, end)) = verify(
+        tuple((
+            nom::character::complete::u16,
+            tag("-"),
+            nom::character::complete::u16,
+        )),
+        |&(start, _, end)| start <= end && end < u16::MAX,
+    )(i)?;
+    Ok((input, Interval::new(start, end)))
+}
+
+pub fn parse_line(i: &[u8]) -> IResult<&[u8], IntervalPair> {
+    let (input, (elf_first, _, elf_second)) =
+        tuple((parse_sections, tag(","), cut(parse_sections)))(i)?;
+    Ok((input, (elf_first, elf_second)))
+}
+
+pub fn parse_input(input: &[u8]) -> IResult<&[u8], Vec<IntervalPair>> {
+    let (input, input_data) = many0(terminated(parse_line, opt(line_ending)))(input)?;
+    eof(input)?;
+    Ok((input, input_data))
+}
+
+/*
+ * Puzzle 1: one assignment fully contains the other
+ */
+pub fn count_containing(input_data: &[IntervalPair]) -> usize {
+    input_data
+        .iter()
+        .filter(|(elf_first, elf_second)| {
+            let h = Hull::hull(elf_first, elf_second);
+            h == *elf_first || h == *elf_second
+        })
+        .count()
+}
+
+/*
+ * Puzzle 2: assignments overlap at all
+ */
+pub fn count_overlapping(input_data: &[IntervalPair]) -> usize {
+    input_data
+        .iter()
+        .filter(|(elf_first, elf_second)| elf_first.overlap(elf_second))
+        .count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<IntervalPair>;
+
+    fn parse(input: &[u8]) -> Result<Self::Input, AocError> {
+        error::finish(input, parse_input(input))
+    }
+
+    fn part1(input_data: &Self::Input) -> Result<String, AocError> {
+        Ok(count_containing(input_data).to_string())
+    }
+
+    fn part2(input_data: &Self::Input) -> Result<String, AocError> {
+        Ok(count_overlapping(input_data).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, Day4};
+    use crate::error::AocError;
+    use crate::solution::Solution;
+
+    #[test]
+    fn test_invalid_sections() {
+        assert_eq!(parse_input(b"1-65534,1-2\n").unwrap().1.len(), 1);
+        for (input, column) in [("1-65535,1-2\n", 1), ("1-2,5-3\n", 5)] {
+            match Day4::parse(input.as_bytes()) {
+                Err(AocError::Parse {
+                    line: 1, column: c, ..
+                }) => assert_eq!(c, column),
+                other => panic!("expected parse error, got {:?}", other.map(|_| ())),
+            }
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use super::IntervalPair;
+use gcollections::ops::{Bounded, IsEmpty};
+use std::fmt::{self, Display};
+
+/*
+ * Allen's interval relations of the first assignment to the second
+ * Sections are whole numbers, so `meets` means adjacent (2-4 and 5-8),
+ * assignments sharing an end section already overlap
+ */
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Relation {
+    Before,
+    Meets,
+    Overlaps,
+    Starts,
+    During,
+    Finishes,
+    Equals,
+    FinishedBy,
+    Contains,
+    StartedBy,
+    OverlappedBy,
+    MetBy,
+    After,
+}
+
+impl Relation {
+    pub const ALL: [Relation; 13] = [
+        Relation::Before,
+        Relation::Meets,
+        Relation::Overlaps,
+        Relation::Starts,
+        Relation::During,
+        Relation::Finishes,
+        Relation::Equals,
+        Relation::FinishedBy,
+        Relation::Contains,
+        Relation::StartedBy,
+        Relation::OverlappedBy,
+        Relation::MetBy,
+        Relation::After,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Relation::Before => "before",
+            Relation::Meets => "meets",
+            Relation::Overlaps => "overlaps",
+            Relation::Starts => "starts",
+            Relation::During => "during",
+            Relation::Finishes => "finishes",
+            Relation::Equals => "equals",
+            Relation::FinishedBy => "finished-by",
+            Relation::Contains => "contains",
+            Relation::StartedBy => "started-by",
+            Relation::OverlappedBy => "overlapped-by",
+            Relation::MetBy => "met-by",
+            Relation::After => "after",
+        }
+    }
+
+    // relation of the second assignment to the first, ALL is symmetric around Equals
+    pub fn inverse(&self) -> Relation {
+        Relation::ALL[Relation::ALL.len() - 1 - *self as usize]
+    }
+
+    // one assignment fully contains the other, puzzle 1
+    pub fn is_containing(&self) -> bool {
+        !self.is_disjoint() && !matches!(self, Relation::Overlaps | Relation::OverlappedBy)
+    }
+
+    // no section in common, puzzle 2 counts the rest
+    pub fn is_disjoint(&self) -> bool {
+        matches!(
+            self,
+            Relation::Before | Relation::Meets | Relation::MetBy | Relation::After
+        )
+    }
+}
+
+impl Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/*
+ * None when an assignment is empty, which the parser never produces
+ */
+pub fn classify((first, second): &IntervalPair) -> Option<Relation> {
+    if first.is_empty() || second.is_empty() {
+        return None;
+    }
+    let (a_low, a_high) = (first.lower() as u32, first.upper() as u32);
+    let (b_low, b_high) = (second.lower() as u32, second.upper() as u32);
+    let relation = if a_high + 1 < b_low {
+        Relation::Before
+    } else if a_high + 1 == b_low {
+        Relation::Meets
+    } else if b_high + 1 < a_low {
+        Relation::After
+    } else if b_high + 1 == a_low {
+        Relation::MetBy
+    } else if (a_low, a_high) == (b_low, b_high) {
+        Relation::Equals
+    } else if a_low == b_low {
+        if a_high < b_high {
+            Relation::Starts
+        } else {
+            Relation::StartedBy
+        }
+    } else if a_high == b_high {
+        if a_low > b_low {
+            Relation::Finishes
+        } else {
+            Relation::FinishedBy
+        }
+    } else if a_low < b_low {
+        if a_high < b_high {
+            Relation::Overlaps
+        } else {
+            Relation::Contains
+        }
+    } else if a_high < b_high {
+        Relation::During
+    } else {
+        Relation::OverlappedBy
+    };
+    Some(relation)
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct RelationCounts {
+    // indexed like Relation::ALL
+    counts: [usize; 13],
+    // pairs with an empty assignment
+    pub empty: usize,
+}
+
+impl RelationCounts {
+    pub fn get(&self, relation: Relation) -> usize {
+        self.counts[relation as usize]
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum::<usize>() + self.empty
+    }
+
+    pub fn containing(&self) -> usize {
+        self.matching(Relation::is_containing)
+    }
+
+    pub fn overlapping(&self) -> usize {
+        self.matching(|relation| !relation.is_disjoint())
+    }
+
+    fn matching(&self, predicate: impl Fn(&Relation) -> bool) -> usize {
+        Relation::ALL
+            .iter()
+            .filter(|relation| predicate(relation))
+            .map(|&relation| self.get(relation))
+            .sum()
+    }
+}
+
+pub fn count_relations(input_data: &[IntervalPair]) -> RelationCounts {
+    let mut counts = RelationCounts::default();
+    for pair in input_data {
+        match classify(pair) {
+            Some(relation) => counts.counts[relation as usize] += 1,
+            None => counts.empty += 1,
+        }
+    }
+    counts
+}
+
+impl Display for RelationCounts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for relation in Relation::ALL {
+            writeln!(f, "{:<14} {:>6}", relation.name(), self.get(relation))?;
+        }
+        if self.empty > 0 {
+            writeln!(f, "{:<14} {:>6}", "empty", self.empty)?;
+        }
+        writeln!(f, "{:<14} {:>6}", "total", self.total())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify, count_relations, Relation};
+    use crate::day4::{count_containing, count_overlapping, parse_input};
+    use interval::ops::Range;
+    use interval::Interval;
+
+    fn relation(a: (u16, u16), b: (u16, u16)) -> Option<Relation> {
+        classify(&(Interval::new(a.0, a.1), Interval::new(b.0, b.1)))
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(relation((2, 3), (5, 8)), Some(Relation::Before));
+        assert_eq!(relation((2, 4), (5, 8)), Some(Relation::Meets));
+        assert_eq!(relation((2, 5), (5, 8)), Some(Relation::Overlaps));
+        assert_eq!(relation((5, 6), (5, 8)), Some(Relation::Starts));
+        assert_eq!(relation((6, 7), (5, 8)), Some(Relation::During));
+        assert_eq!(relation((6, 8), (5, 8)), Some(Relation::Finishes));
+        assert_eq!(relation((5, 8), (5, 8)), Some(Relation::Equals));
+        assert_eq!(relation((9, 9), (5, 8)), Some(Relation::MetBy));
+        assert_eq!(relation((5, 3), (5, 8)), None);
+        for a in [(1, 1), (1, 4), (3, 6), (4, 4), (6, 9)] {
+            for b in [(1, 1), (2, 4), (3, 6), (5, 5), (7, 9)] {
+                let forward = relation(a, b).unwrap();
+                assert_eq!(relation(b, a), Some(forward.inverse()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_counts_match_puzzle() {
+        let input = include_bytes!("../../examples/day4/sample.txt");
+        let (_, pairs) = parse_input(input).unwrap();
+        let counts = count_relations(&pairs);
+        assert_eq!(counts.total(), pairs.len());
+        assert_eq!(counts.containing(), count_containing(&pairs));
+        assert_eq!(counts.overlapping(), count_overlapping(&pairs));
+        assert_eq!(counts.get(Relation::Contains), 1);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::error::{self, AocError};
+use crate::solution::Solution;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, newline, space1};
+use nom::combinator::{eof, opt, recognize};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, preceded, terminated};
+use nom::{AsChar, IResult};
+use std::collections::VecDeque;
+
+pub type Crate = char;
+pub type Stacks = Vec<VecDeque<Crate>>;
+
+#[derive(Debug, PartialEq)]
+pub struct Move {
+    pub amount: u8,
+    pub from: u8,
+    pub to: u8,
+}
+
+pub fn parse_crate(input: &[u8]) -> IResult<&[u8], Option<Crate>> {
+    let (input, maybe_crate) = alt((tag("   "), delimited(tag("["), alpha1, tag("]"))))(input)?;
+    match maybe_crate {
+        b"   " => Ok((input, None)),
+        a_crate => Ok((input, Some(a_crate.first().unwrap().as_char()))),
+    }
+}
+
+pub fn parse_line(input: &[u8]) -> IResult<&[u8], Vec<Option<Crate>>> {
+    let (input, crates_line) = separated_list1(tag(" "), parse_crate)(input)?;
+    Ok((input, crates_line))
+}
+
+pub fn parse_crates_lines(input: &[u8]) -> IResult<&[u8], Vec<VecDeque<Crate>>> {
+    let (input, crates_lines) = many1(terminated(parse_line, newline))(input)?;
+
+    let (rest, crates_numbers) = terminated(
+        many1(preceded(space1, recognize(nom::character::complete::u8))),
+        newline,
+    )(input)?;
+
+    // a crate outside of the numbered stacks
+    if crates_lines
+        .iter()
+        .any(|line| line.len() > crates_numbers.len())
+    {
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Verify,
+        )));
+    }
+
+    let mut stacks = vec![VecDeque::new(); crates_numbers.len()];
+    crates_lines.iter().rev().for_each(|line| {
+        line.iter().enumerate().for_each(|(i, maybe_crate)| {
+            if let Some(a_crate) = maybe_crate {
+                stacks[i].push_front(*a_crate);
+            }
+        })
+    });
+    Ok((rest, stacks))
+}
+
+pub fn parse_move(i: &[u8]) -> IResult<&[u8], Move> {
+    let (input, _) = tag("move ")(i)?;
+    let (input, amount) = nom::character::complete::u8(input)?;
+    let (input, _) = tag(" from ")(input)?;
+    let (input, from) = nom::character::complete::u8(input)?;
+    let (input, _) = tag(" to ")(input)?;
+    let (input, to) = nom::character::complete::u8(input)?;
+    Ok((input, Move { amount, from, to }))
+}
+
+pub fn parse_input(input: &[u8]) -> IResult<&[u8], (Stacks, Vec<Move>)> {
+    let (input, stacks) = parse_crates_lines(input)?;
+    let (input, _) = newline(input)?;
+    let (input, moves) = many1(terminated(parse_move, opt(newline)))(input)?;
+    eof(input)?;
+    Ok((input, (stacks, moves)))
+}
+
+// stacks are numbered from 1
+fn stack_index(stacks: &[VecDeque<Crate>], number: u8) -> Result<usize, AocError> {
+    if number == 0 || number as usize > stacks.len() {
+        return Err(AocError::semantic(format!(
+            "no stack number {} (have {})",
+            number,
+            stacks.len()
+        )));
+    }
+    Ok((number - 1) as usize)
+}
+
+fn check_move(stacks: &[VecDeque<Crate>], m: &Move) -> Result<(usize, usize), AocError> {
+    let from = stack_index(stacks, m.from)?;
+    let to = stack_index(stacks, m.to)?;
+    if stacks[from].len() < m.amount as usize {
+        return Err(AocError::semantic(format!(
+            "cannot move {} crates from stack {} holding {}",
+            m.amount,
+            m.from,
+            stacks[from].len()
+        )));
+    }
+    Ok((from, to))
+}
+
+/*
+ * Puzzle 1: crane moves crates one at a time
+ */
+pub fn rearrange_one_by_one(
+    stacks: &mut [VecDeque<Crate>],
+    moves: &[Move],
+) -> Result<(), AocError> {
+    for m in moves {
+        let (from, to) = check_move(stacks, m)?;
+        for _ in 0..m.amount {
+            let c = stacks[from].pop_front().unwrap();
+            stacks[to].push_front(c);
+        }
+    }
+    Ok(())
+}
+
+/*
+ * Puzzle 2: crane moves all crates at once, keeping their order
+ */
+pub fn rearrange_at_once(stacks: &mut [VecDeque<Crate>], moves: &[Move]) -> Result<(), AocError> {
+    for m in moves {
+        let (from, to) = check_move(stacks, m)?;
+        let mut c = stacks[from]
+            .drain(..(m.amount as usize))
+            .collect::<VecDeque<_>>();
+        c.append(&mut stacks[to]);
+        stacks[to] = c;
+    }
+    Ok(())
+}
+
+pub fn stacks_tops(stacks: &[VecDeque<Crate>]) -> Result<String, AocError> {
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            s.front()
+                .copied()
+                .ok_or_else(|| AocError::semantic(format!("stack {} is empty", i + 1)))
+        })
+        .collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = (Stacks, Vec<Move>);
+
+    fn parse(input: &[u8]) -> Result<Self::Input, AocError> {
+        error::finish(input, parse_input(input))
+    }
+
+    fn part1((stacks, moves): &Self::Input) -> Result<String, AocError> {
+        let mut stacks = stacks.clone();
+        rearrange_one_by_one(&mut stacks, moves)?;
+        stacks_tops(&stacks)
+    }
+
+    fn part2((stacks, moves): &Self::Input) -> Result<String, AocError> {
+        let mut stacks = stacks.clone();
+        rearrange_at_once(&mut stacks, moves)?;
+        stacks_tops(&stacks)
+    }
+}
+
+#[test]
+fn test_parse_line() {
+    let input = b"    [D]     [X]";
+    let expected = vec![None, Some('D'), None, Some('X')];
+    assert_eq!(parse_line(input), Ok((&[][..], expected)));
+}
+
+#[test]
+fn test_parse_crates_lines() {
+    let input = include_bytes!("../../examples/day5/sample.txt");
+    let expected: Vec<VecDeque<Crate>> = vec![
+        VecDeque::from(vec!['N', 'Z']),
+        VecDeque::from(vec!['D', 'C', 'M']),
+        VecDeque::from(vec!['P']),
+    ];
+    let (rest, crates) = parse_crates_lines(input).unwrap();
+    assert_eq!(crates, expected);
+    // the drawing ends with the stack numbers, moves follow after a blank line
+    assert!(rest.starts_with(b"\nmove 1 from 2 to 1\n"));
+}
+
+#[test]
+fn test_parse_move() {
+    let input = b"move 22 from 11 to 3";
+    let expected = Move {
+        amount: 22,
+        from: 11,
+        to: 3,
+    };
+    assert_eq!(parse_move(input), Ok((&b""[..], expected)));
+}
@@ -1,17 +1,19 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use crate::error::AocError;
+use crate::solution::Solution;
+use std::collections::HashMap;
 
 fn insert_or_increment(map: &mut HashMap<u8, usize>, key: u8) {
     let counter = map.entry(key).or_insert(0);
     *counter += 1;
 }
 
-fn find_marker(input: Vec<u8>, marker_size: usize) -> Option<usize> {
+pub fn find_marker(input: &[u8], marker_size: usize) -> Option<usize> {
     if input.len() < marker_size + 1 {
         return None;
     }
     let mut marker_map: HashMap<u8, usize> = HashMap::new();
-    for index in 0..marker_size {
-        insert_or_increment(&mut marker_map, input[index]);
+    for a_char in &input[..marker_size] {
+        insert_or_increment(&mut marker_map, *a_char);
     }
 
     let mut last_char_iter = input.iter();
@@ -35,28 +37,41 @@ fn find_marker(input: Vec<u8>, marker_size: usize) -> Option<usize> {
     None
 }
 
-fn main() {
-    let input = std::fs::read("./src/day6/input.txt").unwrap();
-    match find_marker(input.clone(), 4) {
-        Some(index) => println!("Found marker of size 4 at index {}", index),
-        None => println!("No marker of size 4 found"),
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Vec<u8>;
+
+    fn parse(input: &[u8]) -> Result<Self::Input, AocError> {
+        Ok(input.to_vec())
     }
-    match find_marker(input, 14) {
-        Some(index) => println!("Found marker of size 14 at index {}", index),
-        None => println!("No marker of size 14 found"),
+
+    fn part1(input: &Self::Input) -> Result<String, AocError> {
+        match find_marker(input, 4) {
+            Some(index) => Ok(index.to_string()),
+            None => Err(AocError::semantic("No marker of size 4 found")),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, AocError> {
+        match find_marker(input, 14) {
+            Some(index) => Ok(index.to_string()),
+            None => Err(AocError::semantic("No marker of size 14 found")),
+        }
     }
 }
 
 #[test]
 fn test_puzzle1() {
     let input = b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
-    let marker = find_marker(input.to_vec(), 4);
+    let marker = find_marker(input, 4);
     assert_eq!(marker, Some(10));
 }
 
 #[test]
 fn test_puzzle1_2() {
     let input = b"mjqjpqmgbljsphdztnvjfqwrcgsmlb";
-    let marker = find_marker(input.to_vec(), 14);
+    let marker = find_marker(input, 14);
     assert_eq!(marker, Some(19));
 }
@@ -1,9 +1,10 @@
-use gcollections::ops::Cardinality;
+use crate::error::{self, AocError};
+use crate::solution::Solution;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_till1};
-use nom::character::complete::{digit1, newline};
+use nom::character::complete::newline;
 use nom::character::is_newline;
-use nom::combinator::eof;
+use nom::combinator::{eof, map_res};
 use nom::multi::many1;
 use nom::sequence::terminated;
 use nom::IResult;
@@ -12,14 +13,14 @@ use std::collections::HashSet;
 /*
  * Tree like structure
  */
-type NodeId = usize;
+pub type NodeId = usize;
 
-struct Arena {
-    nodes: Vec<Node>,
+pub struct Arena {
+    pub nodes: Vec<Node>,
 }
 
 impl Arena {
-    fn cd(&self, cwd: NodeId, name: &str) -> Option<NodeId> {
+    pub fn cd(&self, cwd: NodeId, name: &str) -> Option<NodeId> {
         let cwd_dir = match self.nodes.get(cwd) {
             Some(Node::Dir(dir)) => dir,
             _ => return None,
@@ -39,17 +40,16 @@ impl Arena {
         }
     }
 
-    fn has_child(&self, cwd: NodeId, child: &Node) -> bool {
+    pub fn has_child(&self, cwd: NodeId, child: &Node) -> bool {
         match &self.nodes[cwd] {
             Node::Dir(dir) => dir.childrens.iter().any(|&c| &self.nodes[c] == child),
             _ => false,
         }
     }
 
-    fn insert_child(&mut self, cwd: NodeId, mut child: Node) -> Option<NodeId> {
-        match child {
-            Node::Dir(ref mut dir) => dir.parent = Some(cwd),
-            _ => {}
+    pub fn insert_child(&mut self, cwd: NodeId, mut child: Node) -> Option<NodeId> {
+        if let Node::Dir(ref mut dir) = child {
+            dir.parent = Some(cwd);
         }
         self.nodes.push(child);
         let new_id = self.nodes.len() - 1;
@@ -66,7 +66,7 @@ impl Arena {
         }
     }
 
-    fn get_size(&self, node: NodeId) -> u64 {
+    pub fn get_size(&self, node: NodeId) -> u64 {
         match &self.nodes[node] {
             Node::File(file) => file.size,
             Node::Dir(dir) => dir.childrens.iter().map(|&c| self.get_size(c)).sum(),
@@ -75,25 +75,25 @@ impl Arena {
 }
 
 #[derive(Debug, Default, Eq, PartialEq)]
-struct Dir {
-    name: String,
-    childrens: HashSet<NodeId>,
-    parent: Option<NodeId>,
+pub struct Dir {
+    pub name: String,
+    pub childrens: HashSet<NodeId>,
+    pub parent: Option<NodeId>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct File {
-    name: String,
-    size: u64,
+pub struct File {
+    pub name: String,
+    pub size: u64,
 }
 
 #[derive(Debug, PartialEq, Eq)]
-enum Node {
+pub enum Node {
     Dir(Dir),
     File(File),
 }
 
-trait Nodable {
+pub trait Nodable {
     fn size(&self) -> u64;
     fn name(&self) -> &str;
 }
@@ -136,52 +136,49 @@ impl Nodable for Node {
  * Parsing
  */
 #[derive(Debug, PartialEq)]
-enum Command {
+pub enum Command {
     Cd(String),
     Ls(Vec<Node>),
 }
 
-fn parse_ls_line_dir(input: &[u8]) -> IResult<&[u8], Node> {
+fn parse_name(input: &[u8]) -> IResult<&[u8], String> {
+    map_res(take_till1(is_newline), |name: &[u8]| {
+        String::from_utf8(name.to_vec())
+    })(input)
+}
+
+pub fn parse_ls_line_dir(input: &[u8]) -> IResult<&[u8], Node> {
     let (input, _) = tag("dir ")(input)?;
-    let (input, name) = take_till1(is_newline)(input)?;
+    let (input, name) = parse_name(input)?;
     Ok((
         input,
         Node::Dir(Dir {
-            name: String::from_utf8(name.to_vec()).unwrap(),
+            name,
             childrens: HashSet::new(),
             ..Default::default()
         }),
     ))
 }
-fn parse_ls_line_file(input: &[u8]) -> IResult<&[u8], Node> {
-    let (input, size) = digit1(input)?;
+pub fn parse_ls_line_file(input: &[u8]) -> IResult<&[u8], Node> {
+    let (input, size) = nom::character::complete::u64(input)?;
     let (input, _) = tag(" ")(input)?;
-    let (input, name) = take_till1(is_newline)(input)?;
-    Ok((
-        input,
-        Node::File(File {
-            name: String::from_utf8(name.to_vec()).unwrap(),
-            size: String::from_utf8(size.to_vec()).unwrap().parse().unwrap(),
-        }),
-    ))
+    let (input, name) = parse_name(input)?;
+    Ok((input, Node::File(File { name, size })))
 }
-fn parse_ls_line(input: &[u8]) -> IResult<&[u8], Node> {
+pub fn parse_ls_line(input: &[u8]) -> IResult<&[u8], Node> {
     terminated(alt((parse_ls_line_dir, parse_ls_line_file)), newline)(input)
 }
-fn parse_ls(input: &[u8]) -> IResult<&[u8], Command> {
+pub fn parse_ls(input: &[u8]) -> IResult<&[u8], Command> {
     let (input, _) = tag("$ ls\n")(input)?;
     let (input, lines) = many1(parse_ls_line)(input)?;
     Ok((input, Command::Ls(lines)))
 }
-fn parse_cd(input: &[u8]) -> IResult<&[u8], Command> {
+pub fn parse_cd(input: &[u8]) -> IResult<&[u8], Command> {
     let (input, _) = tag("$ cd ")(input)?;
-    let (input, name) = terminated(take_till1(is_newline), newline)(input)?;
-    Ok((
-        input,
-        Command::Cd(String::from_utf8(name.to_vec()).unwrap()),
-    ))
+    let (input, name) = terminated(parse_name, newline)(input)?;
+    Ok((input, Command::Cd(name)))
 }
-fn parse_input(input: &[u8]) -> IResult<&[u8], Vec<Command>> {
+pub fn parse_input(input: &[u8]) -> IResult<&[u8], Vec<Command>> {
     let (input, commands) = many1(alt((parse_ls, parse_cd)))(input)?;
     eof(input)?;
     Ok((input, commands))
@@ -191,11 +188,7 @@ fn parse_input(input: &[u8]) -> IResult<&[u8], Vec<Command>> {
  * Lets go
  */
 
-fn main() {
-    let mut input = std::fs::read("./src/day7/input.txt").unwrap();
-    input.push(b'\n'); // add a newline to make sure the last command is parsed
-    let (_, commands) = parse_input(&input).unwrap();
-
+pub fn build_arena(commands: Vec<Command>) -> Result<Arena, AocError> {
     let mut arena = Arena { nodes: Vec::new() };
     arena.nodes.push(Node::Dir(Dir::default()));
     let mut cwd = 0;
@@ -207,59 +200,98 @@ fn main() {
                 match arena.cd(cwd, name.as_str()) {
                     Some(d) => cwd = d,
                     None => {
-                        panic!("cd: {}: No such file or directory", name);
+                        return Err(AocError::semantic(format!(
+                            "cd: {}: No such file or directory",
+                            name
+                        )));
                     }
                 }
             }
             Command::Ls(nodes) => {
                 // println!("ls");
                 for node in nodes {
-                    if !arena.has_child(cwd, &node) {
-                        arena.insert_child(cwd, node).unwrap();
+                    if !arena.has_child(cwd, &node) && arena.insert_child(cwd, node).is_none() {
+                        return Err(AocError::semantic("ls: cannot add entries to a file"));
                     }
                 }
             }
         }
     }
+    Ok(arena)
+}
+
+/*
+ * Puzzle 1: sum of all directories of size at most 100000
+ */
+pub fn sum_of_small_dirs(arena: &Arena) -> u64 {
     let mut total_sum = 0;
     for i in 0..arena.nodes.len() {
-        match &arena.nodes[i] {
-            Node::Dir(dir) => {
-                let dir_size = arena.get_size(i);
-                if dir_size <= 100000 {
-                    total_sum += dir_size;
-                }
+        if let Node::Dir(_) = &arena.nodes[i] {
+            let dir_size = arena.get_size(i);
+            if dir_size <= 100000 {
+                total_sum += dir_size;
             }
-            _ => {}
         }
     }
-    println!("Puzzle 1: {}", total_sum);
+    total_sum
+}
 
+/*
+ * Puzzle 2: size of the smallest directory that frees enough space
+ */
+pub fn smallest_dir_to_free(arena: &Arena) -> Result<u64, AocError> {
     const FS_SIZE: u64 = 70000000;
     const SIZE_NEEDED: u64 = 30000000;
-    let unused_space = FS_SIZE - arena.get_size(0);
-    let must_free_min = SIZE_NEEDED - unused_space;
+    let unused_space = FS_SIZE.checked_sub(arena.get_size(0)).ok_or_else(|| {
+        AocError::semantic(format!(
+            "used space {} exceeds file system size {}",
+            arena.get_size(0),
+            FS_SIZE
+        ))
+    })?;
+    let must_free_min = SIZE_NEEDED.saturating_sub(unused_space);
 
     let mut current_min_dir_size_to_delete = arena.get_size(0);
     for i in 0..arena.nodes.len() {
-        match &arena.nodes[i] {
-            Node::Dir(dir) => {
-                let dir_size = arena.get_size(i);
-                if dir_size >= must_free_min && dir_size < current_min_dir_size_to_delete {
-                    current_min_dir_size_to_delete = dir_size;
-                }
+        if let Node::Dir(_) = &arena.nodes[i] {
+            let dir_size = arena.get_size(i);
+            if dir_size >= must_free_min && dir_size < current_min_dir_size_to_delete {
+                current_min_dir_size_to_delete = dir_size;
             }
-            _ => {}
         }
     }
-    println!("Puzzle 2: {}", current_min_dir_size_to_delete);
+    Ok(current_min_dir_size_to_delete)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Arena;
+
+    fn parse(input: &[u8]) -> Result<Self::Input, AocError> {
+        let mut input = input.to_vec();
+        // add a newline to make sure the last command is parsed
+        if input.last() != Some(&b'\n') {
+            input.push(b'\n');
+        }
+        let commands = error::finish(&input, parse_input(&input))?;
+        build_arena(commands)
+    }
+
+    fn part1(arena: &Self::Input) -> Result<String, AocError> {
+        Ok(sum_of_small_dirs(arena).to_string())
+    }
+
+    fn part2(arena: &Self::Input) -> Result<String, AocError> {
+        Ok(smallest_dir_to_free(arena)?.to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{
+    use super::{
         parse_input, parse_ls, parse_ls_line_dir, parse_ls_line_file, Command, Dir, File, Node,
-        Size,
     };
     use std::collections::HashSet;
 
@@ -0,0 +1,206 @@
+use crate::bitarray::BitArray;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::solution::Solution;
+use std::io::{BufReader, Read};
+
+pub type Forest = Grid<i8>;
+
+// easy reading of input
+pub fn input_to_forest<T: std::io::Read>(mut reader: BufReader<T>) -> Result<Forest, AocError> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let forest = Grid::parse_with(&input, "a tree height digit", |tree| {
+        tree.to_digit(10).map(|height| height as i8)
+    })?;
+    if forest.is_empty() {
+        return Err(AocError::semantic("empty forest"));
+    }
+    Ok(forest)
+}
+
+/*
+ * Puzzle 1: set a bit corresponding to a tree if the tree is visible
+ */
+pub fn puzzle1_set_visible_trees(bit_array: &mut BitArray, forest: &Forest) {
+    let mut highest_from_top = vec![-1; forest.width()];
+    let mut highest_from_left = vec![-1; forest.height()];
+    for ((x, y), &current_tree) in forest.iter() {
+        if current_tree > highest_from_top[x] {
+            highest_from_top[x] = current_tree;
+            bit_array.set_bit(x, y);
+        }
+        if current_tree > highest_from_left[y] {
+            highest_from_left[y] = current_tree;
+            bit_array.set_bit(x, y);
+        }
+    }
+
+    // now from bottom-right
+    let mut highest_from_bottom = vec![-1; forest.width()];
+    let mut highest_from_right = vec![-1; forest.height()];
+    for ((x, y), &tree) in forest.iter().rev() {
+        if tree > highest_from_bottom[x] {
+            highest_from_bottom[x] = tree;
+            bit_array.set_bit(x, y);
+        }
+        if tree > highest_from_right[y] {
+            highest_from_right[y] = tree;
+            bit_array.set_bit(x, y);
+        }
+    }
+}
+
+/*
+ * Puzzle 2: given single line of trees, compute score of every tree
+ * Takes closure as first as, bcause we want to iterate forest in various directions
+ */
+pub fn score_of_trees_one_line<F>(get_tree_at_index: F, line_size: usize) -> Vec<usize>
+where
+    F: Fn(usize) -> i8,
+{
+    let mut scores: Vec<usize> = Vec::with_capacity(line_size);
+    scores.push(0);
+
+    for current_tree_idx in 1..line_size {
+        let current_tree = get_tree_at_index(current_tree_idx);
+
+        // compare current tree to all previous trees
+        let mut cmp_tree_idx = current_tree_idx - 1;
+        while cmp_tree_idx > 0 {
+            // we found a tree that is higher or equal to the current tree
+            if get_tree_at_index(cmp_tree_idx) >= current_tree {
+                break;
+            }
+            // we found a tree that is lower than the current tree
+            // we can jump over to the tree that is higher or equal to the tree we found
+            cmp_tree_idx -= scores[cmp_tree_idx];
+        }
+
+        scores.push(current_tree_idx - cmp_tree_idx);
+    }
+    scores
+}
+
+/*
+ * Puzzle 2: multiply scores of one line of trees, looking both ways along it
+ * `to_xy` maps index in the line back to forest coordinates
+ */
+fn multiply_line_scores<F>(scores: &mut Grid<usize>, forest_line: &[i8], to_xy: F)
+where
+    F: Fn(usize) -> (usize, usize),
+{
+    let line_size = forest_line.len();
+
+    // forward score
+    score_of_trees_one_line(|idx| forest_line[idx], line_size)
+        .iter()
+        .enumerate()
+        .for_each(|(idx, score_new)| {
+            let (x, y) = to_xy(idx);
+            *scores.get_mut(x, y).unwrap() *= score_new;
+        });
+
+    // backward score
+    score_of_trees_one_line(|idx| forest_line[line_size - idx - 1], line_size)
+        .iter()
+        .rev()
+        .enumerate()
+        .for_each(|(idx, score_new)| {
+            let (x, y) = to_xy(idx);
+            *scores.get_mut(x, y).unwrap() *= score_new;
+        });
+}
+
+/*
+ * Puzzle 2: given a forest, compute score of every tree
+ * Simply call score_of_trees_one_line for every line in all 4 directions
+ */
+pub fn score_of_forest(forest: &Forest) -> Grid<usize> {
+    let mut scores_puzzle2 = Grid::new(forest.width(), forest.height(), 1_usize);
+
+    // left-right and right-left
+    for (y, forest_line) in forest.rows().enumerate() {
+        multiply_line_scores(&mut scores_puzzle2, forest_line, |x| (x, y));
+    }
+
+    // top-bottom and bottom-top
+    for x in 0..forest.width() {
+        let forest_line: Vec<i8> = forest.column(x).unwrap().copied().collect();
+        multiply_line_scores(&mut scores_puzzle2, &forest_line, |y| (x, y));
+    }
+    scores_puzzle2
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Forest;
+
+    fn parse(input: &[u8]) -> Result<Self::Input, AocError> {
+        input_to_forest(BufReader::new(input))
+    }
+
+    fn part1(forest: &Self::Input) -> Result<String, AocError> {
+        let mut visible_trees_map = BitArray::new(forest.width(), forest.height());
+        puzzle1_set_visible_trees(&mut visible_trees_map, forest);
+        Ok(visible_trees_map.sum_bits().to_string())
+    }
+
+    fn part2(forest: &Self::Input) -> Result<String, AocError> {
+        let scores_puzzle2 = score_of_forest(forest);
+        // parsing guarantees a non-empty forest
+        Ok(scores_puzzle2
+            .iter()
+            .map(|(_, score)| score)
+            .max()
+            .unwrap()
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        input_to_forest, puzzle1_set_visible_trees, score_of_forest, score_of_trees_one_line,
+        BitArray,
+    };
+    use crate::grid::Grid;
+    use std::io::BufReader;
+
+    #[test]
+    fn test_score_of_trees_one_line() {
+        let forest_line = vec![2, 6, 1, 2, 2, 3, 5, 8, 6, 11];
+        let scores = score_of_trees_one_line(|idx| forest_line[idx], forest_line.len());
+        assert_eq!(scores, vec![0, 1, 1, 2, 1, 4, 5, 7, 1, 9]);
+
+        let forest_line2 = vec![9, 5, 6, 2, 4, 1, 1, 8, 11];
+        let scores2 = score_of_trees_one_line(|idx| forest_line2[idx], forest_line2.len());
+        assert_eq!(scores2, vec![0, 1, 2, 1, 2, 1, 1, 7, 8]);
+    }
+
+    #[test]
+    fn test_sample() {
+        let input = include_str!("../../examples/day8/sample.txt");
+        let forest = input_to_forest(BufReader::new(input.as_bytes())).unwrap();
+        let mut visible_trees_map = BitArray::new(forest.width(), forest.height());
+        puzzle1_set_visible_trees(&mut visible_trees_map, &forest);
+        println!("{}", visible_trees_map);
+        println!("{:?}", forest);
+        assert_eq!(visible_trees_map.sum_bits(), 21);
+
+        let score2 = score_of_forest(&forest);
+        assert_eq!(
+            score2,
+            Grid::from_rows(vec![
+                vec![0, 0, 0, 0, 0],
+                vec![0, 1, 4, 1, 0],
+                vec![0, 6, 1, 2, 0],
+                vec![0, 1, 8, 3, 0],
+                vec![0, 0, 0, 0, 0]
+            ])
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::solution::Solution;
+use crate::sparse::{Point, PointSet};
+use std::io::{BufRead, BufReader};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point for Position {
+    fn x(&self) -> i64 {
+        self.x as i64
+    }
+
+    fn y(&self) -> i64 {
+        self.y as i64
+    }
+}
+
+/*
+ * Draw knots by their index, the bounding box always includes the start
+ * at (0, 0) and a margin of 2 cells
+ */
+pub fn render_rope(positions: &[Position]) -> Grid<char> {
+    let min_x = positions.iter().map(|p| p.x).min().unwrap_or(0).min(0) - 2;
+    let max_x = positions.iter().map(|p| p.x).max().unwrap_or(0).max(0) + 2;
+    let min_y = positions.iter().map(|p| p.y).min().unwrap_or(0).min(0) - 2;
+    let max_y = positions.iter().map(|p| p.y).max().unwrap_or(0).max(0) + 2;
+
+    let mut grid = Grid::new(
+        (max_x - min_x + 1) as usize,
+        (max_y - min_y + 1) as usize,
+        '.',
+    );
+    // lower knots are drawn last, so the head stays on top
+    for (idx, position) in positions.iter().enumerate().rev() {
+        let knot = std::char::from_digit(idx as u32, 36).unwrap_or('#');
+        grid.set(
+            (position.x - min_x) as usize,
+            (position.y - min_y) as usize,
+            knot,
+        );
+    }
+    grid
+}
+
+pub fn print_rope(positions: &[Position]) {
+    print!("{}", render_rope(positions));
+    positions.iter().enumerate().for_each(|(idx, position)| {
+        print!("{} - {:?}, ", idx, position);
+    });
+    println!("\n");
+}
+
+/*
+ * Moves a knot, returns where next knot should move
+ */
+pub fn move_knot(positions: &mut [Position], knot_idx: usize, dx: i32, dy: i32) -> (i32, i32) {
+    // tricky way to get two mut refs from vector
+    let (heads, tails) = positions.split_at_mut(knot_idx + 1);
+    let head_position = &mut heads[knot_idx];
+    let old_tail_position = &tails[0];
+    let mut new_tail_position = *old_tail_position;
+
+    head_position.x += dx;
+    head_position.y += dy;
+
+    // next knot is still adjacent
+    if (head_position.x - old_tail_position.x).abs() <= 1
+        && (head_position.y - old_tail_position.y).abs() <= 1
+    {
+        return (0, 0);
+    }
+
+    // diagonal move
+    if dx != 0 && dy != 0 {
+        if head_position.x != old_tail_position.x {
+            new_tail_position.x += dx;
+        }
+        if head_position.y != old_tail_position.y {
+            new_tail_position.y += dy;
+        }
+    } else {
+        // standard move (up, down, left, right)
+        if (head_position.x - old_tail_position.x).abs() > 1 {
+            new_tail_position.x += dx;
+            new_tail_position.y = head_position.y
+        } else if (head_position.y - old_tail_position.y).abs() > 1 {
+            new_tail_position.x = head_position.x;
+            new_tail_position.y += dy
+        }
+    }
+
+    (
+        new_tail_position.x - old_tail_position.x,
+        new_tail_position.y - old_tail_position.y,
+    )
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Motion {
+    pub dx: i32,
+    pub dy: i32,
+    pub count: u32,
+}
+
+pub fn parse_motions<T: std::io::Read>(reader: BufReader<T>) -> Result<Vec<Motion>, AocError> {
+    let mut motions = Vec::new();
+
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        let (direction, count) = line.split_once(' ').ok_or_else(|| {
+            AocError::parse(line_idx + 1, 1, &line, "expected `<direction> <count>`")
+        })?;
+
+        let count = count.parse::<u32>().map_err(|err| {
+            AocError::parse(
+                line_idx + 1,
+                direction.len() + 2,
+                &line,
+                format!("invalid count: {}", err),
+            )
+        })?;
+        let (dx, dy) = match direction {
+            "U" => (0, -1),
+            "D" => (0, 1),
+            "L" => (-1, 0),
+            "R" => (1, 0),
+            _ => {
+                return Err(AocError::parse(
+                    line_idx + 1,
+                    1,
+                    &line,
+                    format!("Unknown direction {}", direction),
+                ))
+            }
+        };
+        motions.push(Motion { dx, dy, count });
+    }
+    Ok(motions)
+}
+
+pub fn simulate(motions: &[Motion], knots_amount: usize) -> PointSet<Position> {
+    let mut positions = vec![Position { x: 0, y: 0 }; knots_amount];
+    let mut positions_visited = PointSet::new(); // by tail == positions.last()
+
+    for motion in motions {
+        // repeat move required no of times
+        for _ in 0..motion.count {
+            // move head and first knot, then first know and second knot, etc
+            let (mut dx, mut dy) = (motion.dx, motion.dy);
+            for knot_to_move_as_head in 0..positions.len() - 1 {
+                let (dx_new, dy_new) = move_knot(&mut positions, knot_to_move_as_head, dx, dy);
+                dx = dx_new;
+                dy = dy_new;
+            }
+            let tail = positions.last_mut().unwrap();
+            tail.x += dx;
+            tail.y += dy;
+            positions_visited.insert_point(*tail);
+        }
+        // print_rope(&positions);
+    }
+    positions_visited
+}
+
+pub fn solve<T: std::io::Read>(
+    reader: BufReader<T>,
+    knots_amount: usize,
+) -> Result<PointSet<Position>, AocError> {
+    Ok(simulate(&parse_motions(reader)?, knots_amount))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Motion>;
+
+    fn parse(input: &[u8]) -> Result<Self::Input, AocError> {
+        parse_motions(BufReader::new(input))
+    }
+
+    fn part1(motions: &Self::Input) -> Result<String, AocError> {
+        Ok(simulate(motions, 2).len().to_string())
+    }
+
+    fn part2(motions: &Self::Input) -> Result<String, AocError> {
+        Ok(simulate(motions, 10).len().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_rope, solve, Position};
+    use crate::error::AocError;
+
+    #[test]
+    fn test_simple() {
+        let input = include_str!("../../examples/day9/small.txt");
+        assert_eq!(
+            solve(std::io::BufReader::new(input.as_bytes()), 2)
+                .unwrap()
+                .len(),
+            13
+        );
+        assert_eq!(
+            solve(std::io::BufReader::new(input.as_bytes()), 9)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_big() {
+        let input = include_str!("../../examples/day9/large.txt");
+        assert_eq!(
+            solve(std::io::BufReader::new(input.as_bytes()), 10)
+                .unwrap()
+                .len(),
+            36
+        );
+    }
+
+    #[test]
+    fn test_unknown_direction() {
+        let input = "R 4
+X 2";
+        match solve(std::io::BufReader::new(input.as_bytes()), 2) {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 1)),
+            _ => panic!("expected parse error"),
+        }
+    }
+
+    #[test]
+    fn test_render_rope() {
+        let positions = vec![Position { x: 1, y: -1 }, Position { x: 0, y: 0 }];
+        assert_eq!(
+            render_rope(&positions).to_string(),
+            "......
+......
+...0..
+..1...
+......
+......
+"
+        );
+    }
+}
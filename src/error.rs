@@ -0,0 +1,136 @@
+use nom::IResult;
+use std::fmt::{self, Display};
+use std::io;
+
+#[derive(Debug)]
+pub enum AocError {
+    // line and column are 1-based, snippet is the whole offending line
+    Parse {
+        line: usize,
+        column: usize,
+        snippet: String,
+        message: String,
+    },
+    Semantic(String),
+    Io(io::Error),
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, snippet: &str, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            snippet: snippet.trim_end_matches('\r').to_string(),
+            message: message.into(),
+        }
+    }
+
+    /*
+     * Locate `rest` (a suffix of `input`) and report the line it points into
+     */
+    pub fn parse_at(input: &[u8], rest: &[u8], message: impl Into<String>) -> Self {
+        let offset = input.len() - rest.len();
+        let line_start = input[..offset]
+            .iter()
+            .rposition(|&c| c == b'\n')
+            .map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .iter()
+            .position(|&c| c == b'\n')
+            .map_or(input.len(), |i| offset + i);
+        let line = input[..offset].iter().filter(|&&c| c == b'\n').count() + 1;
+        AocError::parse(
+            line,
+            offset - line_start + 1,
+            &String::from_utf8_lossy(&input[line_start..line_end]),
+            message,
+        )
+    }
+
+    // TOML errors carry a byte span, its start is reported
+    pub fn from_toml(input: &str, err: toml::de::Error) -> Self {
+        let offset = err.span().map_or(0, |span| span.start);
+        AocError::parse_at(input.as_bytes(), &input.as_bytes()[offset..], err.message())
+    }
+
+    pub fn semantic(message: impl Into<String>) -> Self {
+        AocError::Semantic(message.into())
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                snippet,
+                message,
+            } => {
+                writeln!(
+                    f,
+                    "parse error at line {}, column {}: {}",
+                    line, column, message
+                )?;
+                writeln!(f, "  | {}", snippet)?;
+                write!(f, "  | {:>width$}", "^", width = column)
+            }
+            AocError::Semantic(message) => write!(f, "{}", message),
+            AocError::Io(err) => write!(f, "io error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<io::Error> for AocError {
+    fn from(err: io::Error) -> Self {
+        AocError::Io(err)
+    }
+}
+
+/*
+ * Run a nom parser over the whole input, mapping failures to a located parse error
+ */
+pub fn finish<'a, T>(input: &'a [u8], result: IResult<&'a [u8], T>) -> Result<T, AocError> {
+    match result {
+        Ok((_, value)) => Ok(value),
+        Err(nom::Err::Error(err)) | Err(nom::Err::Failure(err)) => {
+            // leftover input is reported by nom as a failed `eof`
+            let message = match err.code {
+                nom::error::ErrorKind::Eof => String::from("unexpected input"),
+                code => format!("unexpected input ({})", code.description()),
+            };
+            Err(AocError::parse_at(input, err.input, message))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(AocError::parse_at(
+            input,
+            &input[input.len()..],
+            "unexpected end of input",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AocError;
+
+    #[test]
+    fn test_parse_at() {
+        let input = b"A Z\nA Xjunk\nB Y";
+        let err = AocError::parse_at(input, &input[7..], "junk");
+        match err {
+            AocError::Parse {
+                line,
+                column,
+                ref snippet,
+                ..
+            } => assert_eq!((line, column, snippet.as_str()), (2, 4, "A Xjunk")),
+            _ => panic!("expected parse error"),
+        }
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 4: junk\n  | A Xjunk\n  |    ^"
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use crate::error::AocError;
+use std::fmt::{self, Display};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+
+    // y grows downwards, like rows of the input
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (1, -1),
+            Direction::DownLeft => (-1, 1),
+            Direction::DownRight => (1, 1),
+        }
+    }
+}
+
+/*
+ * Dense rectangle of cells stored row by row
+ */
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // None if rows are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /*
+     * Parse a block of text, one cell per char, reporting the first char `cell` rejects
+     */
+    pub fn parse_with<F>(input: &str, what: &str, cell: F) -> Result<Self, AocError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (line_idx, line) in input.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let mut row = Vec::with_capacity(line.len());
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    AocError::parse(line_idx + 1, column + 1, line, format!("expected {}", what))
+                })?;
+                row.push(value);
+            }
+            // all lines must be the same length
+            if let Some(first_row) = rows.first() {
+                if first_row.len() != row.len() {
+                    return Err(AocError::parse(
+                        line_idx + 1,
+                        1,
+                        line,
+                        format!("expected {} cells, found {}", first_row.len(), row.len()),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows).unwrap())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(x + y * self.width)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    // returns the old value, None when out of bounds
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Option<T> {
+        self.get_mut(x, y)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl DoubleEndedIterator<Item = &T>> {
+        if x < self.width {
+            Some(self.cells[x..].iter().step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    /*
+     * Coordinates from (x, y) (exclusive) to the edge of the grid
+     */
+    pub fn walk(
+        &self,
+        x: usize,
+        y: usize,
+        direction: Direction,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let (dx, dy) = direction.delta();
+        (1..)
+            .map(move |step| (x as i64 + dx * step, y as i64 + dy * step))
+            .take_while(move |&(x, y)| self.contains(x, y))
+            .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    pub fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        directions.iter().filter_map(move |direction| {
+            let (dx, dy) = direction.delta();
+            let (nx, ny) = (x as i64 + dx, y as i64 + dy);
+            if self.contains(nx, ny) {
+                Some((nx as usize, ny as usize))
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &Direction::ORTHOGONAL)
+    }
+
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &Direction::ALL)
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<u8> {
+    pub fn parse_digits(input: &str) -> Result<Self, AocError> {
+        Grid::parse_with(input, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Self, AocError> {
+        Grid::parse_with(input, "a char", Some)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Grid};
+    use crate::error::AocError;
+
+    #[test]
+    fn test_parse_and_access() {
+        let mut grid = Grid::parse_digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.set(0, 1, 9), Some(4));
+        assert_eq!(grid.set(0, 2, 9), None);
+        assert_eq!(grid.row(1), Some(&[9, 5, 6][..]));
+        assert_eq!(
+            grid.column(1).unwrap().copied().collect::<Vec<_>>(),
+            vec![2, 5]
+        );
+        assert!(grid.column(3).is_none());
+        assert_eq!(grid.to_string(), "123\n956\n");
+
+        assert!(matches!(
+            Grid::parse_digits("12\n1x\n"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Grid::parse_digits("12\n123\n"),
+            Err(AocError::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_walk_and_neighbours() {
+        let grid = Grid::new(3, 3, '.');
+        assert_eq!(
+            grid.walk(1, 1, Direction::Left).collect::<Vec<_>>(),
+            vec![(0, 1)]
+        );
+        assert_eq!(
+            grid.walk(0, 0, Direction::DownRight).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 2)]
+        );
+        assert_eq!(grid.neighbours4(0, 0).count(), 2);
+        assert_eq!(grid.neighbours8(0, 0).count(), 3);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    }
+}
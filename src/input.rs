@@ -0,0 +1,131 @@
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u8,
+    pub source: InputSource,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "cannot read input for day {} from {}: {}",
+            self.day, self.source, self.error
+        )?;
+        if self.error.kind() == io::ErrorKind::NotFound {
+            write!(
+                f,
+                " (pass --input <path>, --input - for stdin, or set {})",
+                INPUT_DIR_VAR
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/*
+ * Inputs live in `<dir>/dayN/input.txt`, the repo's own `src` dir unless overridden
+ */
+pub fn default_input_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+pub fn input_path(input_dir: &Path, day: u8) -> PathBuf {
+    input_dir.join(format!("day{}", day)).join("input.txt")
+}
+
+// explicit path wins, "-" means stdin, then the input dir
+pub fn resolve(day: u8, input: Option<&Path>, input_dir: Option<&Path>) -> InputSource {
+    match input {
+        Some(path) if path == Path::new("-") => InputSource::Stdin,
+        Some(path) => InputSource::File(path.to_path_buf()),
+        None => match input_dir {
+            Some(dir) => InputSource::File(input_path(dir, day)),
+            None => InputSource::File(input_path(&default_input_dir(), day)),
+        },
+    }
+}
+
+pub fn read(day: u8, source: InputSource) -> Result<Vec<u8>, InputError> {
+    let result = match &source {
+        InputSource::Stdin => {
+            let mut buf = Vec::new();
+            io::stdin().read_to_end(&mut buf).map(|_| buf)
+        }
+        InputSource::File(path) => std::fs::read(path),
+    };
+    result.map_err(|error| InputError { day, source, error })
+}
+
+// like `read`, but hands out a reader for inputs too large to keep in memory
+pub fn open(day: u8, source: InputSource) -> Result<Box<dyn BufRead>, InputError> {
+    let result = match &source {
+        InputSource::Stdin => Ok(Box::new(io::stdin().lock()) as Box<dyn BufRead>),
+        InputSource::File(path) => {
+            File::open(path).map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+        }
+    };
+    result.map_err(|error| InputError { day, source, error })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{input_path, read, resolve, InputSource};
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(resolve(3, Some(Path::new("-")), None), InputSource::Stdin);
+        assert_eq!(
+            resolve(3, Some(Path::new("a.txt")), Some(Path::new("/inputs"))),
+            InputSource::File(PathBuf::from("a.txt"))
+        );
+        assert_eq!(
+            resolve(3, None, Some(Path::new("/inputs"))),
+            InputSource::File(PathBuf::from("/inputs/day3/input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_default_input_exists() {
+        assert_eq!(
+            resolve(1, None, None),
+            InputSource::File(input_path(&super::default_input_dir(), 1))
+        );
+        assert!(read(1, resolve(1, None, None)).is_ok());
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let err = read(4, InputSource::File(PathBuf::from("/nonexistent/x.txt"))).unwrap_err();
+        assert_eq!(err.error.kind(), io::ErrorKind::NotFound);
+        assert!(err
+            .to_string()
+            .starts_with("cannot read input for day 4 from /nonexistent/x.txt: "));
+    }
+}
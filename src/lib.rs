@@ -0,0 +1,20 @@
+/*
+ * Advent of Code 2022 solvers, dispatched by the `aoc` runner
+ */
+pub mod bench;
+pub mod bitarray;
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod solution;
+pub mod sparse;
+pub mod verify;
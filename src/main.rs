@@ -0,0 +1,680 @@
+use aoc_2022::bench;
+use aoc_2022::day1;
+use aoc_2022::day2::{self, config::PartialConfig, tournament};
+use aoc_2022::day3::{
+    self,
+    priority::{Alphabet, Letters, Priority, UnicodeLetters, Weights},
+};
+use aoc_2022::day4;
+use aoc_2022::error::{self, AocError};
+use aoc_2022::input::{self, InputSource};
+use aoc_2022::solution::{find_day, Answers, Day, Part, DAYS};
+use aoc_2022::verify;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every registered day with --all
+    Run(RunArgs),
+    /// Solve every day and compare against the answers.toml stored next to its input
+    Verify(VerifyArgs),
+    /// Time parsing and both parts over repeated runs
+    Bench(BenchArgs),
+    /// Day 1 analyses of a calorie log, streamed so it may not fit in memory
+    #[command(subcommand)]
+    Day1(Day1Command),
+    /// Day 2 analyses of a strategy guide
+    #[command(subcommand)]
+    Day2(Day2Command),
+    /// Day 3 checks and analyses of rucksack lists
+    #[command(subcommand)]
+    Day3(Day3Command),
+    /// Day 4 analyses of section assignments
+    #[command(subcommand)]
+    Day4(Day4Command),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Part to solve, both when omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input file, or - to read from stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Directory holding dayN/input.txt files, defaults to the crate's src dir
+    #[arg(long, env = input::INPUT_DIR_VAR)]
+    input_dir: Option<PathBuf>,
+
+    /// Solve every registered day and print a results table
+    #[arg(long, conflicts_with_all = ["day", "part", "input"])]
+    all: bool,
+
+    /// Also print how long parsing and each part took
+    #[arg(long)]
+    time: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Directory holding dayN/input.txt and dayN/answers.toml files
+    #[arg(long, env = input::INPUT_DIR_VAR)]
+    input_dir: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ReportFormat {
+    Text,
+    Json,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark, every registered day when omitted
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// How many times to solve each day
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Report format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Directory holding dayN/input.txt files, defaults to the crate's src dir
+    #[arg(long, env = input::INPUT_DIR_VAR)]
+    input_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Puzzle input file, or - to read from stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Directory holding dayN/input.txt files, defaults to the crate's src dir
+    #[arg(long, env = input::INPUT_DIR_VAR)]
+    input_dir: Option<PathBuf>,
+}
+
+impl InputArgs {
+    fn source(&self, day: u8) -> InputSource {
+        input::resolve(day, self.input.as_deref(), self.input_dir.as_deref())
+    }
+}
+
+#[derive(Subcommand)]
+enum Day1Command {
+    /// Elves carrying the most calories, and their combined total
+    Top {
+        /// How many elves to report
+        #[arg(short, long, default_value_t = 3)]
+        k: usize,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Item counts, calorie distribution and ties for the maximum
+    Stats {
+        /// Percentiles of calorie totals to report
+        #[arg(
+            long,
+            value_delimiter = ',',
+            default_values_t = [25, 75, 90, 99],
+            value_parser = clap::value_parser!(u8).range(0..=100)
+        )]
+        percentiles: Vec<u8>,
+
+        /// Number of histogram buckets
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        buckets: u32,
+
+        /// Report format
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+// flags win over the config file, missing keys keep the puzzle's values
+#[derive(Args)]
+struct Day2ConfigArgs {
+    /// TOML file with any of opponent, player, shape_scores, outcome_scores
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Opponent letters for Rock, Paper, Scissors, e.g. ABC
+    #[arg(long)]
+    opponent: Option<String>,
+
+    /// Player letters for Rock, Paper, Scissors, e.g. XYZ
+    #[arg(long)]
+    player: Option<String>,
+
+    /// Points for playing Rock, Paper, Scissors
+    #[arg(long, value_delimiter = ',')]
+    shape_scores: Option<Vec<u32>>,
+
+    /// Points for a loss, draw and win
+    #[arg(long, value_delimiter = ',')]
+    outcome_scores: Option<Vec<u32>>,
+}
+
+impl Day2ConfigArgs {
+    fn load(self) -> Result<day2::config::Config, Box<dyn Error>> {
+        let file = match &self.config {
+            Some(path) => {
+                PartialConfig::load(path).map_err(|err| format!("{}: {}", path.display(), err))?
+            }
+            None => PartialConfig::default(),
+        };
+        let flags = PartialConfig {
+            opponent: self.opponent,
+            player: self.player,
+            shape_scores: self.shape_scores,
+            outcome_scores: self.outcome_scores,
+        };
+        Ok(flags.or(file).build()?)
+    }
+}
+
+#[derive(Subcommand)]
+enum Day2Command {
+    /// Score both parts under a different letter mapping or scoring scheme
+    Score {
+        /// Print every round with its moves, outcome and points for both parts
+        #[arg(long)]
+        explain: bool,
+
+        #[command(flatten)]
+        config: Day2ConfigArgs,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Score the guide under every reading of the second column, as moves or outcomes
+    Decode {
+        #[command(flatten)]
+        config: Day2ConfigArgs,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Play every pair of strategies against each other, `guide` and `elves` replay the guide
+    Tournament {
+        /// Strategies taking part: always-rock, random, frequency, mirror, guide, elves
+        #[arg(
+            long,
+            value_delimiter = ',',
+            default_value = "always-rock,random,frequency,mirror,guide,elves"
+        )]
+        strategies: Vec<tournament::Kind>,
+
+        /// Rounds per match, as many as the guide has when omitted, at most 10000000
+        #[arg(long)]
+        rounds: Option<usize>,
+
+        /// Seed of the random strategy
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        #[command(flatten)]
+        config: Day2ConfigArgs,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+#[derive(Subcommand)]
+enum Day3Command {
+    /// Report every malformed rucksack and group instead of stopping at the first
+    Check {
+        /// Also solve both parts, skipping rucksacks and groups with problems
+        #[arg(long)]
+        keep_going: bool,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// List every item common to each group of rucksacks, with priorities
+    Groups {
+        /// Rucksacks per group
+        #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+        size: u32,
+
+        /// disjoint groups like part 2, or sliding windows of `size` rucksacks
+        #[arg(long, default_value = "disjoint")]
+        grouping: day3::groups::Grouping,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Solve both parts with another item catalogue, the puzzle's letters by default
+    Score {
+        /// Items in order of priority, the first one is worth 1
+        #[arg(long, group = "scheme")]
+        alphabet: Option<String>,
+
+        /// Any Unicode letter, worth its code point
+        #[arg(long, group = "scheme")]
+        unicode: bool,
+
+        /// TOML file mapping each item to its weight
+        #[arg(long, group = "scheme")]
+        weights: Option<PathBuf>,
+
+        /// Rucksacks per group sharing one badge
+        #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+        size: u32,
+
+        /// disjoint groups like part 2, or sliding windows of `size` rucksacks
+        #[arg(long, default_value = "disjoint")]
+        grouping: day3::groups::Grouping,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+#[derive(Subcommand)]
+enum Day4Command {
+    /// Merge every assignment and report covered sections, gaps and counts per section
+    Coverage {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Count pairs by Allen interval relation (before, meets, overlaps, ...)
+    Relations {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+fn lookup_day(day: u8) -> Result<&'static Day, Box<dyn Error>> {
+    find_day(day).ok_or_else(|| format!("Day {} is not solved yet", day).into())
+}
+
+fn selected_parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(1) => vec![Part::One],
+        Some(2) => vec![Part::Two],
+        _ => vec![Part::One, Part::Two],
+    }
+}
+
+fn solve_day(day: &Day, source: InputSource, parts: &[Part]) -> Result<Answers, Box<dyn Error>> {
+    let input = input::read(day.day, source)?;
+    (day.run)(&input, parts).map_err(|err| format!("day {}: {}", day.day, err).into())
+}
+
+fn run_one(
+    day: &Day,
+    source: InputSource,
+    parts: &[Part],
+    time: bool,
+) -> Result<(), Box<dyn Error>> {
+    let answers = solve_day(day, source, parts)?;
+    if time {
+        println!("Day {} parse: {:?}", day.day, answers.parse_elapsed);
+    }
+    for answer in answers.parts {
+        if time {
+            println!(
+                "Day {} part {}: {} ({:?})",
+                day.day, answer.part, answer.value, answer.elapsed
+            );
+        } else {
+            println!("Day {} part {}: {}", day.day, answer.part, answer.value);
+        }
+    }
+    Ok(())
+}
+
+// keeps going past failing days, so one bad input does not hide the other results
+fn run_all(input_dir: Option<PathBuf>, time: bool) -> Result<(), Box<dyn Error>> {
+    let mut failed = 0;
+    println!("Day  {:<20}  Part 2", "Part 1");
+    for day in DAYS.iter() {
+        let source = input::resolve(day.day, None, input_dir.as_deref());
+        match solve_day(day, source, &[Part::One, Part::Two]) {
+            Ok(answers) if time => println!(
+                "{:>3}  {:<20}  {:<20}  parse {:?}, part 1 {:?}, part 2 {:?}",
+                day.day,
+                answers.parts[0].value,
+                answers.parts[1].value,
+                answers.parse_elapsed,
+                answers.parts[0].elapsed,
+                answers.parts[1].elapsed
+            ),
+            Ok(answers) => println!(
+                "{:>3}  {:<20}  {}",
+                day.day, answers.parts[0].value, answers.parts[1].value
+            ),
+            Err(err) => {
+                failed += 1;
+                println!("{:>3}  {:<20}  error", day.day, "error");
+                eprintln!("{}", err);
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{} of {} days failed", failed, DAYS.len()).into());
+    }
+    Ok(())
+}
+
+// None when the day has no stored answers
+fn verify_day(
+    day: &Day,
+    input_dir: Option<&Path>,
+) -> Result<Option<Vec<verify::Mismatch>>, Box<dyn Error>> {
+    let source = input::resolve(day.day, None, input_dir);
+    let expected = match &source {
+        InputSource::File(path) => verify::load_expected(&verify::answers_path(path))
+            .map_err(|err| format!("day {}: answers.toml: {}", day.day, err))?,
+        InputSource::Stdin => None,
+    };
+    let expected = match expected {
+        Some(expected) => expected,
+        None => return Ok(None),
+    };
+    let answers = solve_day(day, source, &[Part::One, Part::Two])?;
+    Ok(Some(verify::compare(day.day, &expected, &answers.parts)))
+}
+
+fn verify_all(input_dir: Option<PathBuf>) -> Result<(), Box<dyn Error>> {
+    let mut failed = 0;
+    for day in DAYS.iter() {
+        match verify_day(day, input_dir.as_deref()) {
+            Ok(None) => println!("day {}: no stored answers, skipped", day.day),
+            Ok(Some(mismatches)) if mismatches.is_empty() => println!("day {}: ok", day.day),
+            Ok(Some(mismatches)) => {
+                failed += 1;
+                for mismatch in mismatches {
+                    println!("{}", mismatch);
+                }
+            }
+            Err(err) => {
+                failed += 1;
+                eprintln!("{}", err);
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{} of {} days failed verification", failed, DAYS.len()).into());
+    }
+    Ok(())
+}
+
+fn bench_days(args: BenchArgs) -> Result<(), Box<dyn Error>> {
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![lookup_day(day)?],
+        None => DAYS.iter().collect(),
+    };
+
+    let mut rows = Vec::new();
+    for day in days {
+        let source = input::resolve(day.day, None, args.input_dir.as_deref());
+        let input = input::read(day.day, source)?;
+        let day_rows = bench::bench(day, &input, args.runs as usize)
+            .map_err(|err| format!("day {}: {}", day.day, err))?;
+        rows.extend(day_rows);
+    }
+
+    match args.format {
+        Format::Text => print!("{}", bench::Table(&rows)),
+        Format::Json => println!("{}", bench::to_json(&rows)),
+        Format::Csv => print!("{}", bench::to_csv(&rows)),
+    }
+    Ok(())
+}
+
+fn day1_command(command: Day1Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Day1Command::Top { k, input } => {
+            let reader = input::open(1, input.source(1))?;
+            let top = day1::top_k_totals(reader, k).map_err(|err| format!("day 1: {}", err))?;
+            for (rank, elf) in top.iter().enumerate() {
+                println!("{:>3}. elf {}: {}", rank + 1, elf.number, elf.calories);
+            }
+            let total =
+                day1::total_calories(top.iter().map(|elf| elf.calories)).ok_or_else(|| {
+                    AocError::semantic(format!(
+                        "day 1: sum of the top {} elves overflows",
+                        top.len()
+                    ))
+                })?;
+            println!("top {} total: {}", top.len(), total);
+        }
+        Day1Command::Stats {
+            percentiles,
+            buckets,
+            format,
+            input,
+        } => {
+            let input = input::read(1, input.source(1))?;
+            let report = day1::parse_ants(input.as_slice())
+                .and_then(|ants| day1::stats::report(&ants, &percentiles, buckets as usize))
+                .map_err(|err| format!("day 1: {}", err))?;
+            match format {
+                ReportFormat::Text => print!("{}", report),
+                ReportFormat::Json => println!("{}", report.to_json()),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_guide(
+    config: Day2ConfigArgs,
+    input: InputArgs,
+) -> Result<(day2::config::Config, Vec<day2::PRSRound>), Box<dyn Error>> {
+    let config = config.load()?;
+    let input = input::read(2, input.source(2))?;
+    let game = error::finish(&input, day2::parse_game_with(&input, &config.encoding))
+        .map_err(|err| format!("day 2: {}", err))?;
+    Ok((config, game))
+}
+
+fn day2_command(command: Day2Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Day2Command::Score {
+            explain,
+            config,
+            input,
+        } => {
+            let (config, game) = read_guide(config, input)?;
+            if explain {
+                let explanation = day2::explain::Explanation::new(&game, &config)
+                    .map_err(|err| format!("day 2: {}", err))?;
+                print!("{}", explanation);
+            }
+            println!(
+                "Day 2 part 1: {}",
+                day2::score_strategy_with(&game, &config.scoring)
+                    .map_err(|err| format!("day 2: {}", err))?
+            );
+            println!(
+                "Day 2 part 2: {}",
+                day2::score_strategy_fixed_with(&game, &config.scoring)
+                    .map_err(|err| format!("day 2: {}", err))?
+            );
+        }
+        Day2Command::Decode { config, input } => {
+            let (config, game) = read_guide(config, input)?;
+            let report = day2::decode::decode(&game, config.encoding.player, &config.scoring)
+                .map_err(|err| format!("day 2: {}", err))?;
+            print!("{}", report);
+        }
+        Day2Command::Tournament {
+            strategies,
+            rounds,
+            seed,
+            config,
+            input,
+        } => {
+            let (config, game) = read_guide(config, input)?;
+            let rounds = rounds.unwrap_or(game.len());
+            let results =
+                tournament::round_robin(&strategies, &game, rounds, seed, &config.scoring)
+                    .map_err(|err| format!("day 2: {}", err))?;
+            print!("{}", tournament::Table(&results));
+        }
+    }
+    Ok(())
+}
+
+// both parts under any priority scheme
+fn score_rucksacks<P: Priority>(
+    input: &[u8],
+    priority: &P,
+    size: usize,
+    grouping: day3::groups::Grouping,
+) -> Result<(), Box<dyn Error>> {
+    let rucksacks =
+        day3::parse_rucksacks_with(input, priority).map_err(|err| format!("day 3: {}", err))?;
+    let part1 = day3::sum_of_shared_items_with(&rucksacks, priority)
+        .map_err(|err| format!("day 3: {}", err))?;
+    println!("Day 3 part 1: {}", part1);
+    let part2 = day3::sum_of_badges_with(&rucksacks, size, grouping, priority)
+        .map_err(|err| format!("day 3: {}", err))?;
+    println!("Day 3 part 2: {}", part2);
+    Ok(())
+}
+
+fn day3_command(command: Day3Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Day3Command::Check { keep_going, input } => {
+            let input = input::read(3, input.source(3))?;
+            let validation = day3::validate::validate(input.as_slice())?;
+            for problem in &validation.problems {
+                println!("{}", problem);
+            }
+            if keep_going {
+                println!("Day 3 part 1: {}", validation.sum_of_shared_items());
+                println!("Day 3 part 2: {}", validation.sum_of_badges());
+            }
+            if !validation.is_valid() {
+                return Err(format!("day 3: {} problems found", validation.problems.len()).into());
+            }
+            println!("day 3: ok");
+        }
+        Day3Command::Groups {
+            size,
+            grouping,
+            input,
+        } => {
+            let input = input::read(3, input.source(3))?;
+            let reports = day3::parse_rucksacks(input.as_slice())
+                .and_then(|rucksacks| {
+                    day3::groups::analyse_groups(&rucksacks, size as usize, grouping)
+                })
+                .map_err(|err| format!("day 3: {}", err))?;
+            for report in &reports {
+                println!("{}", report);
+            }
+            println!(
+                "sum of common item priorities: {}",
+                day3::groups::sum_of_common_items(&reports)
+            );
+        }
+        Day3Command::Score {
+            alphabet,
+            unicode,
+            weights,
+            size,
+            grouping,
+            input,
+        } => {
+            let input = input::read(3, input.source(3))?;
+            let (size, input) = (size as usize, input.as_slice());
+            match (alphabet, weights) {
+                (Some(alphabet), _) => {
+                    score_rucksacks(input, &Alphabet::new(&alphabet)?, size, grouping)?
+                }
+                (_, Some(path)) => score_rucksacks(input, &Weights::load(&path)?, size, grouping)?,
+                _ if unicode => score_rucksacks(input, &UnicodeLetters, size, grouping)?,
+                _ => score_rucksacks(input, &Letters, size, grouping)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_assignments(input: InputArgs) -> Result<Vec<day4::IntervalPair>, Box<dyn Error>> {
+    let input = input::read(4, input.source(4))?;
+    Ok(
+        error::finish(&input, day4::parse_input(&input))
+            .map_err(|err| format!("day 4: {}", err))?,
+    )
+}
+
+fn day4_command(command: Day4Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Day4Command::Coverage { input } => {
+            let pairs = read_assignments(input)?;
+            print!("{}", day4::coverage::coverage(&pairs));
+        }
+        Day4Command::Relations { input } => {
+            let pairs = read_assignments(input)?;
+            print!("{}", day4::relation::count_relations(&pairs));
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) if args.all => run_all(args.input_dir, args.time),
+        Command::Run(args) => {
+            let day_number = args.day.unwrap();
+            let source =
+                input::resolve(day_number, args.input.as_deref(), args.input_dir.as_deref());
+            lookup_day(day_number)
+                .and_then(|day| run_one(day, source, &selected_parts(args.part), args.time))
+        }
+        Command::Verify(args) => verify_all(args.input_dir),
+        Command::Bench(args) => bench_days(args),
+        Command::Day1(command) => day1_command(command),
+        Command::Day2(command) => day2_command(command),
+        Command::Day3(command) => day3_command(command),
+        Command::Day4(command) => day4_command(command),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
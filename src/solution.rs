@@ -0,0 +1,102 @@
+use crate::error::AocError;
+use crate::{day1, day2, day3, day4, day5, day6, day7, day8, day9};
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Answers {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<Answer>,
+}
+
+/*
+ * Every day parses its input once and then solves both parts from the parsed form
+ */
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+
+    fn parse(input: &[u8]) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Result<String, AocError>;
+    fn part2(input: &Self::Input) -> Result<String, AocError>;
+}
+
+// every phase is timed separately, so parsing cost does not hide in the answers
+pub fn run<S: Solution>(input: &[u8], parts: &[Part]) -> Result<Answers, AocError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let value = match part {
+                Part::One => S::part1(&parsed)?,
+                Part::Two => S::part2(&parsed)?,
+            };
+            Ok(Answer {
+                part,
+                value,
+                elapsed: start.elapsed(),
+            })
+        })
+        .collect::<Result<Vec<_>, AocError>>()?;
+    Ok(Answers {
+        parse_elapsed,
+        parts,
+    })
+}
+
+/*
+ * Registry of all solved days, used by the runner to dispatch by number
+ */
+pub struct Day {
+    pub day: u8,
+    pub run: fn(&[u8], &[Part]) -> Result<Answers, AocError>,
+}
+
+const fn register<S: Solution>() -> Day {
+    Day {
+        day: S::DAY,
+        run: run::<S>,
+    }
+}
+
+pub static DAYS: [Day; 9] = [
+    register::<day1::Day1>(),
+    register::<day2::Day2>(),
+    register::<day3::Day3>(),
+    register::<day4::Day4>(),
+    register::<day5::Day5>(),
+    register::<day6::Day6>(),
+    register::<day7::Day7>(),
+    register::<day8::Day8>(),
+    register::<day9::Day9>(),
+];
+
+pub fn find_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,235 @@
+use crate::bitarray::BitArray;
+use std::collections::hash_map::{self, HashMap};
+use std::fmt::{self, Display};
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+/*
+ * Anything with integer coordinates, which may be negative
+ */
+pub trait Point: Copy + Eq + Hash {
+    fn x(&self) -> i64;
+    fn y(&self) -> i64;
+}
+
+impl Point for (i64, i64) {
+    fn x(&self) -> i64 {
+        self.0
+    }
+
+    fn y(&self) -> i64 {
+        self.1
+    }
+}
+
+/*
+ * Smallest rectangle containing every point, both corners inclusive
+ */
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Bounds {
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+
+impl Bounds {
+    pub fn at(x: i64, y: i64) -> Self {
+        Bounds {
+            min_x: x,
+            min_y: y,
+            max_x: x,
+            max_y: y,
+        }
+    }
+
+    pub fn include(&mut self, x: i64, y: i64) {
+        self.min_x = self.min_x.min(x);
+        self.min_y = self.min_y.min(y);
+        self.max_x = self.max_x.max(x);
+        self.max_y = self.max_y.max(y);
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y + 1) as usize
+    }
+
+    // offset of (x, y) from the top-left corner, None when outside
+    pub fn to_dense(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        if self.contains(x, y) {
+            Some(((x - self.min_x) as usize, (y - self.min_y) as usize))
+        } else {
+            None
+        }
+    }
+}
+
+/*
+ * Unbounded grid storing only the cells that were set
+ * Bounds only ever grow, so there is no remove
+ */
+#[derive(Debug, Clone)]
+pub struct SparseGrid<P: Point, T> {
+    cells: HashMap<P, T>,
+    bounds: Option<Bounds>,
+}
+
+// a sparse grid with no values, only which points were visited
+pub type PointSet<P> = SparseGrid<P, ()>;
+
+impl<P: Point, T> Default for SparseGrid<P, T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<P: Point, T> SparseGrid<P, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // returns the old value, if the point was already set
+    pub fn insert(&mut self, point: P, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(point.x(), point.y()),
+            None => self.bounds = Some(Bounds::at(point.x(), point.y())),
+        }
+        self.cells.insert(point, value)
+    }
+
+    pub fn get(&self, point: &P) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    pub fn get_mut(&mut self, point: &P) -> Option<&mut T> {
+        self.cells.get_mut(point)
+    }
+
+    pub fn contains(&self, point: &P) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // None while the grid is empty
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> hash_map::Iter<'_, P, T> {
+        self.cells.iter()
+    }
+
+    pub fn points(&self) -> hash_map::Keys<'_, P, T> {
+        self.cells.keys()
+    }
+
+    /*
+     * Dense copy of which cells are set, (0, 0) of the bit array is the top-left
+     * corner of the bounds
+     */
+    pub fn to_bit_array(&self) -> (BitArray, Bounds) {
+        let bounds = self.bounds.unwrap_or(Bounds::at(0, 0));
+        let (width, height) = if self.is_empty() {
+            (0, 0)
+        } else {
+            (bounds.width(), bounds.height())
+        };
+        let mut bit_array = BitArray::new(width, height);
+        for point in self.points() {
+            let (x, y) = bounds.to_dense(point.x(), point.y()).unwrap();
+            bit_array.set_bit(x, y);
+        }
+        (bit_array, bounds)
+    }
+}
+
+impl<P: Point> SparseGrid<P, ()> {
+    // true if the point was not in the set yet
+    pub fn insert_point(&mut self, point: P) -> bool {
+        self.insert(point, ()).is_none()
+    }
+}
+
+impl<P: Point, T> Extend<(P, T)> for SparseGrid<P, T> {
+    fn extend<I: IntoIterator<Item = (P, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<P: Point, T> FromIterator<(P, T)> for SparseGrid<P, T> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+// set cells as `#`, the rest as `.`, cropped to the bounds
+impl<P: Point, T> Display for SparseGrid<P, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_bit_array().0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bounds, PointSet, SparseGrid};
+
+    #[test]
+    fn test_bounds_grow() {
+        let mut points: PointSet<(i64, i64)> = PointSet::new();
+        assert_eq!(points.bounds(), None);
+        assert!(points.insert_point((0, 0)));
+        assert!(points.insert_point((-2, 1)));
+        assert!(points.insert_point((1, -1)));
+        assert!(!points.insert_point((0, 0)));
+        assert_eq!(points.len(), 3);
+        assert_eq!(
+            points.bounds(),
+            Some(Bounds {
+                min_x: -2,
+                min_y: -1,
+                max_x: 1,
+                max_y: 1
+            })
+        );
+        assert_eq!(points.to_string(), "...#\n..#.\n#...\n");
+    }
+
+    #[test]
+    fn test_to_bit_array() {
+        let grid: SparseGrid<(i64, i64), char> =
+            vec![((-5, -5), 'a'), ((-4, -3), 'b')].into_iter().collect();
+        let (bit_array, bounds) = grid.to_bit_array();
+        assert_eq!((bounds.width(), bounds.height()), (2, 3));
+        assert!(bit_array.check_bit(0, 0));
+        assert!(bit_array.check_bit(1, 2));
+        assert_eq!(bit_array.sum_bits(), 2);
+        assert_eq!(bounds.to_dense(-4, -3), Some((1, 2)));
+        assert_eq!(bounds.to_dense(-6, -3), None);
+
+        let empty: PointSet<(i64, i64)> = PointSet::new();
+        assert_eq!(empty.to_bit_array().0.sum_bits(), 0);
+        assert_eq!(empty.to_string(), "");
+    }
+}
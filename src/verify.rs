@@ -0,0 +1,150 @@
+use crate::error::AocError;
+use crate::solution::{Answer, Part};
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+/*
+ * Known good answers, kept in `answers.toml` next to the day's input:
+ *
+ *   part1 = 68787
+ *   part2 = "BSDMQFLSP"
+ */
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+pub fn answers_path(input_path: &Path) -> PathBuf {
+    input_path.with_file_name("answers.toml")
+}
+
+pub fn parse_expected(input: &str) -> Result<Expected, AocError> {
+    let table: toml::Table = input
+        .parse()
+        .map_err(|err| AocError::from_toml(input, err))?;
+
+    let mut expected = Expected::default();
+    for (key, value) in table {
+        let answer = match value {
+            toml::Value::String(s) => s,
+            toml::Value::Integer(i) => i.to_string(),
+            other => {
+                return Err(AocError::semantic(format!(
+                    "answer `{}` must be a string or an integer, got {}",
+                    key,
+                    other.type_str()
+                )))
+            }
+        };
+        match key.as_str() {
+            "part1" => expected.part1 = Some(answer),
+            "part2" => expected.part2 = Some(answer),
+            _ => return Err(AocError::semantic(format!("unknown answer key `{}`", key))),
+        }
+    }
+    Ok(expected)
+}
+
+// a missing file means the day has no stored answers yet
+pub fn load_expected(path: &Path) -> Result<Option<Expected>, AocError> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => parse_expected(&content).map(Some),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "day {} part {}:", self.day, self.part)?;
+        writeln!(f, "  - {}", self.expected)?;
+        write!(f, "  + {}", self.actual)
+    }
+}
+
+pub fn compare(day: u8, expected: &Expected, answers: &[Answer]) -> Vec<Mismatch> {
+    answers
+        .iter()
+        .filter_map(|answer| {
+            let expected = expected.get(answer.part)?;
+            if expected == answer.value {
+                return None;
+            }
+            Some(Mismatch {
+                day,
+                part: answer.part,
+                expected: expected.to_string(),
+                actual: answer.value.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, parse_expected, Expected};
+    use crate::error::AocError;
+    use crate::solution::{Answer, Part};
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_expected() {
+        let expected = parse_expected("part1 = 68787\npart2 = \"BSDMQFLSP\"\n").unwrap();
+        assert_eq!(
+            expected,
+            Expected {
+                part1: Some(String::from("68787")),
+                part2: Some(String::from("BSDMQFLSP")),
+            }
+        );
+        assert!(matches!(
+            parse_expected("part1 = 1\npart2 = \n"),
+            Err(AocError::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            parse_expected("part3 = 1"),
+            Err(AocError::Semantic(_))
+        ));
+    }
+
+    #[test]
+    fn test_compare() {
+        let expected = Expected {
+            part1: Some(String::from("1")),
+            part2: None,
+        };
+        let answers = vec![
+            Answer {
+                part: Part::One,
+                value: String::from("2"),
+                elapsed: Duration::ZERO,
+            },
+            Answer {
+                part: Part::Two,
+                value: String::from("3"),
+                elapsed: Duration::ZERO,
+            },
+        ];
+        let mismatches = compare(4, &expected, &answers);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].to_string(), "day 4 part 1:\n  - 1\n  + 2");
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * Runs every solver against the puzzle examples in examples/dayN/<name>.txt,
+ * comparing with the answers stored in examples/dayN/<name>.toml
+ */
+use aoc_2022::solution::{find_day, Part, DAYS};
+use aoc_2022::verify;
+use std::path::{Path, PathBuf};
+
+fn examples_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(format!("day{}", day))
+}
+
+fn example_inputs(day: u8) -> Vec<PathBuf> {
+    let mut inputs: Vec<PathBuf> = std::fs::read_dir(examples_dir(day))
+        .unwrap_or_else(|err| panic!("day {}: no examples: {}", day, err))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    inputs.sort();
+    inputs
+}
+
+fn check_examples(day: u8) {
+    let solver = find_day(day).unwrap();
+    let inputs = example_inputs(day);
+    assert!(!inputs.is_empty(), "day {}: no example inputs", day);
+
+    let mut failures = Vec::new();
+    for input_path in inputs {
+        let name = input_path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        let expected = verify::load_expected(&input_path.with_extension("toml"))
+            .unwrap_or_else(|err| panic!("day {} {}: {}", day, name, err))
+            .unwrap_or_else(|| panic!("day {} {}: no expected answers", day, name));
+
+        let input = std::fs::read(&input_path).unwrap();
+        match (solver.run)(&input, &[Part::One, Part::Two]) {
+            Ok(answers) => {
+                for mismatch in verify::compare(day, &expected, &answers.parts) {
+                    failures.push(format!("{}: {}", name, mismatch));
+                }
+            }
+            Err(err) => failures.push(format!("{}: {}", name, err)),
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+macro_rules! example_tests {
+    ($($name:ident => $day:expr),* $(,)?) => {
+        const COVERED: &[u8] = &[$($day),*];
+        $(
+            #[test]
+            fn $name() {
+                check_examples($day);
+            }
+        )*
+    };
+}
+
+example_tests! {
+    day1 => 1,
+    day2 => 2,
+    day3 => 3,
+    day4 => 4,
+    day5 => 5,
+    day6 => 6,
+    day7 => 7,
+    day8 => 8,
+    day9 => 9,
+}
+
+#[test]
+fn every_day_has_examples() {
+    for day in DAYS.iter() {
+        assert!(
+            COVERED.contains(&day.day),
+            "day {} is missing from example_tests!",
+            day.day
+        );
+    }
+}